@@ -0,0 +1,78 @@
+//! Compares `auto_grid`, which splits the area with integer arithmetic, with the same grid laid
+//! out by ratatui's `Layout` solver.
+//!
+//! Run with `cargo bench`, or `cargo bench --features smallvec` to also compare the `Vec` and
+//! `SmallVec` return types.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use ratatui_auto_grid::auto_grid;
+use ratatui_auto_grid::ratatui::layout::{Constraint, Layout, Rect};
+
+/// Lays out the grid of `auto_grid` with one `Layout` for the rows and one for the columns.
+fn layout_grid(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let cols = n.isqrt() + usize::from(n.isqrt() * n.isqrt() < n);
+    let rows = n.div_ceil(cols);
+
+    let row_areas = Layout::vertical(vec![Constraint::Ratio(1, rows as u32); rows])
+        .spacing(spacing)
+        .split(area);
+    let col_areas = Layout::horizontal(vec![Constraint::Ratio(1, cols as u32); cols])
+        .spacing(spacing)
+        .split(area);
+    row_areas
+        .iter()
+        .flat_map(|row| {
+            col_areas.iter().map(|col| Rect {
+                y: row.y,
+                height: row.height,
+                ..*col
+            })
+        })
+        .take(n)
+        .collect()
+}
+
+fn small_grids(c: &mut Criterion) {
+    let area = Rect::new(0, 0, 400, 200);
+
+    let mut group = c.benchmark_group("grid");
+    for n in [4, 16, 64, 256] {
+        group.bench_with_input(BenchmarkId::new("auto_grid", n), &n, |b, &n| {
+            b.iter(|| auto_grid(black_box(area), black_box(n), black_box(1)));
+        });
+        group.bench_with_input(BenchmarkId::new("Layout", n), &n, |b, &n| {
+            b.iter(|| layout_grid(black_box(area), black_box(n), black_box(1)));
+        });
+    }
+    group.finish();
+}
+
+fn tall_grid(c: &mut Criterion) {
+    // 100 rows of 100 columns.
+    let tall = Rect::new(0, 0, 1000, 500);
+    c.bench_function("auto_grid/10000", |b| {
+        b.iter(|| auto_grid(black_box(tall), black_box(10_000), black_box(0)));
+    });
+}
+
+#[cfg(feature = "smallvec")]
+fn return_types(c: &mut Criterion) {
+    let area = Rect::new(0, 0, 400, 200);
+
+    let mut group = c.benchmark_group("return type");
+    group.bench_function("Vec/4", |b| {
+        b.iter(|| auto_grid(black_box(area), black_box(4), black_box(1)));
+    });
+    group.bench_function("SmallVec/4", |b| {
+        b.iter(|| ratatui_auto_grid::auto_grid_small(black_box(area), black_box(4), black_box(1)));
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "smallvec"))]
+criterion_group!(benches, small_grids, tall_grid);
+#[cfg(feature = "smallvec")]
+criterion_group!(benches, small_grids, tall_grid, return_types);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+//! Sets the `ratatui_widgets` cfg when the crate is built against a full ratatui rather than the
+//! `no_std` ratatui-core, which has no `Block` or `WidgetRef`.
+
+use std::env;
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(ratatui_widgets)");
+    if env::var_os("CARGO_FEATURE_RATATUI_028").is_some()
+        || env::var_os("CARGO_FEATURE_RATATUI_LATEST").is_some()
+    {
+        println!("cargo::rustc-cfg=ratatui_widgets");
+    }
+}
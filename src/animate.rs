@@ -0,0 +1,257 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use ratatui::layout::Rect;
+
+use crate::new_rect;
+
+/// Interpolates between two layouts, cell by cell.
+///
+/// The position and size of each cell move linearly from `from` to `to` as `t` goes from `0.0`
+/// to `1.0`, rounded to the nearest cell. `t` is clamped to that range. When the layouts have a
+/// different number of cells, the cells past the end of the shorter one are taken as they are
+/// from the longer one.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, lerp_grids};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let (from, to) = (auto_grid(area, 1, 0), auto_grid(area, 4, 0));
+///
+/// let halfway = lerp_grids(&from, &to, 0.5);
+/// assert_eq!(halfway[0], Rect::new(0, 0, 75, 75));
+/// assert_eq!(halfway[1..], to[1..]);
+/// ```
+pub fn lerp_grids(from: &[Rect], to: &[Rect], t: f32) -> Vec<Rect> {
+    let t = progress(t);
+    let longer = if from.len() > to.len() { from } else { to };
+    from.iter()
+        .zip(to)
+        .map(|(&a, &b)| lerp_rect(a, b, t))
+        .chain(longer.iter().skip(from.len().min(to.len())).copied())
+        .collect()
+}
+
+/// Interpolates between two layouts, making the cells that are only in one of them grow in or
+/// shrink out.
+///
+/// Like [`lerp_grids`], the cells at the same index move linearly from `from` to `to` as `t` goes
+/// from `0.0` to `1.0`, so `t = 0.0` gives `from` and `t = 1.0` gives `to` exactly. A cell past the
+/// end of `from` starts as a zero-size rect at the position of its cell in `to`, and a cell past
+/// the end of `to` ends as a zero-size rect at the position of its cell in `from`.
+///
+/// # Returns
+///
+/// As many cells as the longer of the two layouts.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, lerp_layout};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let (from, to) = (auto_grid(area, 1, 0), auto_grid(area, 4, 0));
+///
+/// let halfway = lerp_layout(&from, &to, 0.5);
+/// assert_eq!(halfway[0], Rect::new(0, 0, 75, 75));
+/// // Growing in from the top-left corner of its final cell.
+/// assert_eq!(halfway[3], Rect::new(50, 50, 25, 25));
+/// ```
+pub fn lerp_layout(from: &[Rect], to: &[Rect], t: f32) -> Vec<Rect> {
+    let t = progress(t);
+    let collapsed = |cell: Rect| new_rect(cell.x, cell.y, 0, 0);
+
+    (0..from.len().max(to.len()))
+        .map(|index| match (from.get(index), to.get(index)) {
+            (Some(&a), Some(&b)) => lerp_rect(a, b, t),
+            (Some(&a), None) => lerp_rect(a, collapsed(a), t),
+            (None, Some(&b)) => lerp_rect(collapsed(b), b, t),
+            (None, None) => unreachable!("index below the longer length"),
+        })
+        .collect()
+}
+
+/// An animation from one layout to another, driven by the elapsed time.
+///
+/// The transition doesn't keep time itself: the application measures the time since the layout
+/// changed and asks for the frame at that point, typically on every tick of its event loop.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{GridTransition, auto_grid};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let transition = GridTransition::new(
+///     auto_grid(area, 1, 0),
+///     auto_grid(area, 4, 0),
+///     Duration::from_millis(200),
+/// );
+///
+/// assert_eq!(transition.frame(Duration::from_millis(100))[0], Rect::new(0, 0, 75, 75));
+/// assert!(transition.is_finished(Duration::from_millis(200)));
+/// assert_eq!(transition.frame(Duration::from_secs(1)), auto_grid(area, 4, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridTransition {
+    from: Vec<Rect>,
+    to: Vec<Rect>,
+    duration: Duration,
+}
+
+impl GridTransition {
+    /// Creates a transition from the `from` layout to the `to` layout lasting `duration`.
+    pub fn new(from: Vec<Rect>, to: Vec<Rect>, duration: Duration) -> Self {
+        Self { from, to, duration }
+    }
+
+    /// Returns the layout the transition starts from.
+    pub fn from(&self) -> &[Rect] {
+        &self.from
+    }
+
+    /// Returns the layout the transition ends on.
+    pub fn to(&self) -> &[Rect] {
+        &self.to
+    }
+
+    /// Returns the duration of the transition.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns whether the transition is over after `elapsed`.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration
+    }
+
+    /// Returns the cells of the frame shown after `elapsed`, interpolated with [`lerp_layout`].
+    ///
+    /// Once the transition is over, this is the final layout.
+    pub fn frame(&self, elapsed: Duration) -> Vec<Rect> {
+        if self.is_finished(elapsed) {
+            return self.to.clone();
+        }
+
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        lerp_layout(&self.from, &self.to, t)
+    }
+}
+
+/// Clamps an interpolation factor to `0.0..=1.0`, treating NaN as `0.0`.
+fn progress(t: f32) -> f32 {
+    if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) }
+}
+
+/// Interpolates each field of a rect, rounding to the nearest cell.
+fn lerp_rect(a: Rect, b: Rect, t: f32) -> Rect {
+    let lerp = |a: u16, b: u16| {
+        let (a, b) = (f32::from(a), f32::from(b));
+        // Never negative, so truncating after adding ½ rounds without `f32::round` from `std`.
+        (a + (b - a) * t + 0.5) as u16
+    };
+    new_rect(
+        lerp(a.x, b.x),
+        lerp(a.y, b.y),
+        lerp(a.width, b.width),
+        lerp(a.height, b.height),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[test]
+    fn endpoints() {
+        let area = Rect::new(3, 7, 91, 53);
+        let (from, to) = (auto_grid(area, 5, 1), auto_grid(area, 5, 4));
+
+        assert_eq!(lerp_grids(&from, &to, 0.0), from);
+        assert_eq!(lerp_grids(&from, &to, 1.0), to);
+        assert_eq!(lerp_grids(&from, &to, -1.0), from);
+        assert_eq!(lerp_grids(&from, &to, 2.0), to);
+    }
+
+    #[test]
+    fn remainder_passes_through() {
+        let area = Rect::new(0, 0, 100, 100);
+        let (few, many) = (auto_grid(area, 2, 0), auto_grid(area, 9, 0));
+
+        let growing = lerp_grids(&few, &many, 0.3);
+        assert_eq!(growing.len(), 9);
+        assert_eq!(growing[2..], many[2..]);
+
+        let shrinking = lerp_grids(&many, &few, 0.3);
+        assert_eq!(shrinking.len(), 9);
+        assert_eq!(shrinking[2..], many[2..]);
+    }
+
+    #[test]
+    fn halfway() {
+        let from = [Rect::new(0, 0, 10, 10)];
+        let to = [Rect::new(10, 20, 30, 0)];
+        assert_eq!(lerp_grids(&from, &to, 0.5), [Rect::new(5, 10, 20, 5)]);
+    }
+
+    #[test]
+    fn layout_endpoints() {
+        let area = Rect::new(3, 7, 91, 53);
+        let (from, to) = (auto_grid(area, 7, 1), auto_grid(area, 7, 3));
+
+        assert_eq!(lerp_layout(&from, &to, 0.0), from);
+        assert_eq!(lerp_layout(&from, &to, 1.0), to);
+    }
+
+    #[test]
+    fn cells_grow_in_and_shrink_out() {
+        let area = Rect::new(0, 0, 100, 100);
+        let (few, many) = (auto_grid(area, 2, 0), auto_grid(area, 9, 0));
+
+        let growing = lerp_layout(&few, &many, 0.0);
+        assert_eq!(growing[..2], few[..]);
+        for (cell, target) in growing[2..].iter().zip(&many[2..]) {
+            assert_eq!(*cell, Rect::new(target.x, target.y, 0, 0));
+        }
+        assert_eq!(lerp_layout(&few, &many, 1.0), many);
+
+        let shrinking = lerp_layout(&many, &few, 1.0);
+        assert_eq!(shrinking.len(), 9);
+        assert_eq!(shrinking[..2], few[..]);
+        for (cell, origin) in shrinking[2..].iter().zip(&many[2..]) {
+            assert_eq!(*cell, Rect::new(origin.x, origin.y, 0, 0));
+        }
+    }
+
+    #[test]
+    fn transition_frames() {
+        let area = Rect::new(0, 0, 100, 100);
+        let (from, to) = (auto_grid(area, 4, 0), auto_grid(area, 9, 2));
+        let transition = GridTransition::new(from.clone(), to.clone(), Duration::from_secs(2));
+
+        assert_eq!(
+            transition.frame(Duration::ZERO),
+            lerp_layout(&from, &to, 0.0)
+        );
+        assert_eq!(
+            transition.frame(Duration::from_millis(500)),
+            lerp_layout(&from, &to, 0.25)
+        );
+        assert!(!transition.is_finished(Duration::from_millis(1999)));
+        assert_eq!(transition.frame(Duration::from_secs(2)), to);
+
+        let instant = GridTransition::new(from, to.clone(), Duration::ZERO);
+        assert_eq!(instant.frame(Duration::ZERO), to);
+    }
+}
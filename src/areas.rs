@@ -0,0 +1,257 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::collections::HashMap;
+use std::fmt;
+
+use ratatui::layout::Rect;
+
+use crate::split_grid;
+
+/// A grid layout described by naming each of its slots, similar to CSS `grid-template-areas`.
+///
+/// Each name must cover a rectangular region of slots. Slots sharing a name are merged into one
+/// rect, including the spacing between them.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::GridTemplate;
+///
+/// let template = GridTemplate::new(&[&["header", "header"], &["sidebar", "main"]]).unwrap();
+/// let areas = template.split(Rect::new(0, 0, 100, 50), 0);
+///
+/// assert_eq!(areas["header"], Rect::new(0, 0, 100, 25));
+/// assert_eq!(areas["sidebar"], Rect::new(0, 25, 50, 25));
+/// assert_eq!(areas["main"], Rect::new(50, 25, 50, 25));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridTemplate<'a> {
+    rows: u16,
+    cols: u16,
+    regions: Vec<Region<'a>>,
+}
+
+/// The slots covered by a name, as inclusive row and column bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Region<'a> {
+    name: &'a str,
+    top: u16,
+    left: u16,
+    bottom: u16,
+    right: u16,
+}
+
+impl<'a> GridTemplate<'a> {
+    /// Creates a template from rows of slot names.
+    ///
+    /// Returns an error if the template is empty, if it has more than `u16::MAX` rows or columns,
+    /// if the rows have different lengths or if a name doesn't cover a rectangular region.
+    pub fn new(rows: &[&[&'a str]]) -> Result<Self, GridTemplateError> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if cols == 0 {
+            return Err(GridTemplateError::Empty);
+        }
+        let (Ok(row_count), Ok(col_count)) = (u16::try_from(rows.len()), u16::try_from(cols))
+        else {
+            return Err(GridTemplateError::TooLarge {
+                rows: rows.len(),
+                cols,
+            });
+        };
+
+        let mut regions: Vec<Region<'a>> = Vec::new();
+        for (r, row) in (0..row_count).zip(rows) {
+            if row.len() != cols {
+                return Err(GridTemplateError::RaggedRow {
+                    row: usize::from(r),
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+
+            for (c, &name) in (0..col_count).zip(row.iter()) {
+                match regions.iter_mut().find(|region| region.name == name) {
+                    Some(region) => {
+                        region.top = region.top.min(r);
+                        region.left = region.left.min(c);
+                        region.bottom = region.bottom.max(r);
+                        region.right = region.right.max(c);
+                    }
+                    None => regions.push(Region {
+                        name,
+                        top: r,
+                        left: c,
+                        bottom: r,
+                        right: c,
+                    }),
+                }
+            }
+        }
+
+        for region in &regions {
+            let covered = (region.top..=region.bottom).all(|r| {
+                (region.left..=region.right).all(|c| rows[r as usize][c as usize] == region.name)
+            });
+            if !covered {
+                return Err(GridTemplateError::NonRectangular {
+                    name: region.name.to_string(),
+                });
+            }
+        }
+
+        Ok(Self {
+            rows: row_count,
+            cols: col_count,
+            regions,
+        })
+    }
+
+    /// Splits `area` according to the template and returns the rect of each name.
+    pub fn split(&self, area: Rect, spacing: u16) -> HashMap<&'a str, Rect> {
+        let slots = split_grid(area, self.rows, self.cols, spacing, spacing);
+        let slot = |r: u16, c: u16| slots[usize::from(r) * usize::from(self.cols) + usize::from(c)];
+
+        self.regions
+            .iter()
+            .map(|region| {
+                let first = slot(region.top, region.left);
+                let last = slot(region.bottom, region.right);
+                (region.name, first.union(last))
+            })
+            .collect()
+    }
+}
+
+/// An error returned when a [`GridTemplate`] is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridTemplateError {
+    /// The template has no slots.
+    Empty,
+    /// The template has more than `u16::MAX` rows or columns.
+    TooLarge {
+        /// Number of rows in the template.
+        rows: usize,
+        /// Number of slots in the first row.
+        cols: usize,
+    },
+    /// A row doesn't have the same number of slots as the first row.
+    RaggedRow {
+        /// Index of the offending row.
+        row: usize,
+        /// Number of slots in the first row.
+        expected: usize,
+        /// Number of slots in the offending row.
+        found: usize,
+    },
+    /// The slots sharing a name don't form a rectangle.
+    NonRectangular {
+        /// The offending name.
+        name: String,
+    },
+}
+
+impl fmt::Display for GridTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid template has no slots"),
+            Self::TooLarge { rows, cols } => write!(
+                f,
+                "grid template has {rows} rows of {cols} slots, at most {} of each are supported",
+                u16::MAX
+            ),
+            Self::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "grid template row {row} has {found} slots, expected {expected}"
+            ),
+            Self::NonRectangular { name } => {
+                write!(f, "grid template area `{name}` is not rectangular")
+            }
+        }
+    }
+}
+
+impl core::error::Error for GridTemplateError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn merges_spanning_names() {
+        let template = GridTemplate::new(&[
+            &["header", "header", "header"],
+            &["sidebar", "main", "main"],
+            &["sidebar", "main", "main"],
+        ])
+        .unwrap();
+        let areas = template.split(Rect::new(0, 0, 90, 90), 0);
+
+        assert_eq!(areas.len(), 3);
+        assert_eq!(areas["header"], Rect::new(0, 0, 90, 30));
+        assert_eq!(areas["sidebar"], Rect::new(0, 30, 30, 60));
+        assert_eq!(areas["main"], Rect::new(30, 30, 60, 60));
+    }
+
+    #[test]
+    fn merged_area_includes_spacing() {
+        let template = GridTemplate::new(&[&["a", "a"], &["b", "c"]]).unwrap();
+        let areas = template.split(Rect::new(0, 0, 101, 51), 1);
+
+        assert_eq!(areas["a"].x, areas["b"].x);
+        assert_eq!(areas["a"].right(), areas["c"].right());
+        assert_eq!(areas["a"].bottom() + 1, areas["b"].y);
+    }
+
+    #[test]
+    fn rejects_non_rectangular_areas() {
+        let result = GridTemplate::new(&[&["a", "a"], &["a", "b"]]);
+        assert_eq!(
+            result,
+            Err(GridTemplateError::NonRectangular {
+                name: "a".to_string()
+            })
+        );
+
+        let result = GridTemplate::new(&[&["a", "b", "a"]]);
+        assert!(matches!(
+            result,
+            Err(GridTemplateError::NonRectangular { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_ragged_and_empty_templates() {
+        assert_eq!(
+            GridTemplate::new(&[&["a", "b"], &["c"]]),
+            Err(GridTemplateError::RaggedRow {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(GridTemplate::new(&[]), Err(GridTemplateError::Empty));
+    }
+
+    #[test]
+    fn rejects_templates_too_large_for_u16() {
+        let row = vec!["a"; usize::from(u16::MAX) + 1];
+        assert_eq!(
+            GridTemplate::new(&[&row]),
+            Err(GridTemplateError::TooLarge {
+                rows: 1,
+                cols: usize::from(u16::MAX) + 1
+            })
+        );
+
+        let row = vec!["a"; usize::from(u16::MAX)];
+        assert!(GridTemplate::new(&[&row]).is_ok());
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use ratatui::layout::Rect;
+
+use crate::grid_cells;
+
+/// Picks the number of columns of a grid from the width of its area, like CSS media queries.
+///
+/// Each breakpoint is a `(min_width, cols)` pair: areas at least `min_width` wide use `cols`
+/// columns, the breakpoint with the largest matching `min_width` winning. Areas narrower than the
+/// smallest breakpoint use a single column.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::Breakpoints;
+///
+/// let breakpoints = Breakpoints::new(&[(60, 2), (120, 3)]).unwrap();
+/// assert_eq!(breakpoints.columns(59), 1);
+/// assert_eq!(breakpoints.columns(60), 2);
+/// assert_eq!(breakpoints.columns(150), 3);
+///
+/// let cells = breakpoints.split(Rect::new(0, 0, 80, 20), 4, 0);
+/// assert_eq!(cells[1], Rect::new(40, 0, 40, 10));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Breakpoints {
+    breakpoints: Vec<(u16, u16)>,
+}
+
+impl Breakpoints {
+    /// Creates breakpoints from `(min_width, cols)` pairs.
+    ///
+    /// Returns an error if the pairs are not sorted by `min_width`, if a `min_width` appears more
+    /// than once or if a column count is 0.
+    pub fn new(breakpoints: &[(u16, u16)]) -> Result<Self, BreakpointsError> {
+        if let Some(&(min_width, _)) = breakpoints.iter().find(|&&(_, cols)| cols == 0) {
+            return Err(BreakpointsError::ZeroColumns { min_width });
+        }
+
+        for pair in breakpoints.windows(2) {
+            let (previous, min_width) = (pair[0].0, pair[1].0);
+            if previous == min_width {
+                return Err(BreakpointsError::Duplicate { min_width });
+            }
+            if previous > min_width {
+                return Err(BreakpointsError::Unsorted { min_width });
+            }
+        }
+
+        Ok(Self {
+            breakpoints: breakpoints.to_vec(),
+        })
+    }
+
+    /// Returns the number of columns used for an area `width` wide.
+    pub fn columns(&self, width: u16) -> u16 {
+        self.breakpoints
+            .iter()
+            .rev()
+            .find(|&&(min_width, _)| width >= min_width)
+            .map_or(1, |&(_, cols)| cols)
+    }
+
+    /// Arranges `n` items in a grid whose column count is picked from the width of `area`.
+    ///
+    /// Rows are added as needed to fit all the items, filled in row-major order. Like
+    /// [`auto_grid`](crate::auto_grid), the grid has at most `u16::MAX` rows, and the items past
+    /// them come back as zero-size rects at the origin of `area`.
+    pub fn split(&self, area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+        let cols = self.columns(area.width);
+        let rows = n.div_ceil(usize::from(cols)).min(usize::from(u16::MAX)) as u16;
+        grid_cells(area, n, (rows, cols), spacing, spacing)
+    }
+}
+
+/// An error returned when [`Breakpoints`] are invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakpointsError {
+    /// A breakpoint has a smaller `min_width` than the one before it.
+    Unsorted {
+        /// The offending minimum width.
+        min_width: u16,
+    },
+    /// Two breakpoints have the same `min_width`.
+    Duplicate {
+        /// The duplicated minimum width.
+        min_width: u16,
+    },
+    /// A breakpoint has 0 columns.
+    ZeroColumns {
+        /// The minimum width of the offending breakpoint.
+        min_width: u16,
+    },
+}
+
+impl fmt::Display for BreakpointsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsorted { min_width } => {
+                write!(f, "breakpoint at width {min_width} is not sorted")
+            }
+            Self::Duplicate { min_width } => {
+                write!(f, "breakpoint at width {min_width} is duplicated")
+            }
+            Self::ZeroColumns { min_width } => {
+                write!(f, "breakpoint at width {min_width} has no columns")
+            }
+        }
+    }
+}
+
+impl core::error::Error for BreakpointsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_columns_from_width() {
+        let breakpoints = Breakpoints::new(&[(60, 2), (120, 3)]).unwrap();
+
+        for (width, cols) in [(0, 1), (59, 1), (60, 2), (119, 2), (120, 3), (500, 3)] {
+            assert_eq!(breakpoints.columns(width), cols, "width {width}");
+        }
+
+        let result = breakpoints.split(Rect::new(0, 0, 150, 20), 5, 0);
+        assert_eq!(result.len(), 5);
+        assert_eq!(result[2], Rect::new(100, 0, 50, 10));
+        assert_eq!(result[3], Rect::new(0, 10, 50, 10));
+
+        let result = breakpoints.split(Rect::new(0, 0, 40, 30), 3, 0);
+        assert!(result.iter().all(|cell| cell.width == 40));
+    }
+
+    #[test]
+    fn rows_saturate_for_large_n() {
+        let breakpoints = Breakpoints::new(&[]).unwrap();
+        let area = Rect::new(0, 0, 10, 100);
+        for n in [65_535, 65_536, 100_000] {
+            let result = breakpoints.split(area, n, 0);
+            assert_eq!(result.len(), n);
+            assert_eq!(result.iter().filter(|cell| !cell.is_empty()).count(), 100);
+            assert_eq!(result[n - 1], Rect::ZERO);
+        }
+    }
+
+    #[test]
+    fn validates_breakpoints() {
+        assert_eq!(
+            Breakpoints::new(&[(120, 3), (60, 2)]),
+            Err(BreakpointsError::Unsorted { min_width: 60 })
+        );
+        assert_eq!(
+            Breakpoints::new(&[(60, 2), (60, 3)]),
+            Err(BreakpointsError::Duplicate { min_width: 60 })
+        );
+        assert_eq!(
+            Breakpoints::new(&[(60, 0)]),
+            Err(BreakpointsError::ZeroColumns { min_width: 60 })
+        );
+        assert!(Breakpoints::new(&[]).is_ok());
+    }
+}
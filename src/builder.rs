@@ -0,0 +1,955 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use ratatui::layout::{Alignment, Margin, Rect};
+
+use crate::fast::split_axis;
+use crate::{DimensionHint, collapse_empty, empty_cell, grid_cells, grid_shape, new_rect};
+
+/// The dimensions of a grid computed from the number of items and the area.
+type DimensionFn = dyn Fn(usize, Rect) -> (u16, u16);
+
+/// How the items of an [`AutoGrid`] are distributed between its rows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Balance {
+    /// Fills every row but the last one, so that the cells line up in columns.
+    ///
+    /// For `n = 7` in 3 rows of 3 columns, this gives rows of 3, 3 and 1 cells.
+    #[default]
+    Columns,
+    /// Spreads the items over the rows so that their lengths differ by at most one, the extra items
+    /// going to the top rows.
+    ///
+    /// Each row shares the width of the area between its own cells, so the cells of shorter rows
+    /// are wider and no longer line up with the cells of the other rows. For `n = 7` in 3 rows,
+    /// this gives rows of 3, 2 and 2 cells.
+    Rows,
+}
+
+/// Where the cells of a partial last row of an [`AutoGrid`] are placed horizontally.
+///
+/// The cells keep the width of the columns above them, only their position changes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gravity {
+    /// Lines the cells up with the first columns.
+    #[default]
+    Left,
+    /// Centers the cells, rounding towards the left.
+    Center,
+    /// Lines the cells up with the last columns, so that the last cell ends on the right edge.
+    Right,
+    /// Spreads the cells from the left edge to the right edge, with gutters between them that
+    /// differ by at most one.
+    ///
+    /// A single cell stays on the left.
+    Justify,
+}
+
+/// Where the cells of an [`AutoGrid`] are placed vertically when they are shorter than the area.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VGravity {
+    /// Keeps the cells against the top edge.
+    #[default]
+    Top,
+    /// Centers the cells, rounding towards the top.
+    Middle,
+    /// Keeps the cells against the bottom edge.
+    Bottom,
+}
+
+impl From<VGravity> for Alignment {
+    fn from(gravity: VGravity) -> Self {
+        match gravity {
+            VGravity::Top => Self::Left,
+            VGravity::Middle => Self::Center,
+            VGravity::Bottom => Self::Right,
+        }
+    }
+}
+
+/// A reusable configuration of an automatic grid layout.
+///
+/// Without any option, [`AutoGrid::split`] gives the same cells as [`auto_grid`](crate::auto_grid).
+/// Like [`auto_grid`](crate::auto_grid), it always returns exactly one cell per item.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{AutoGrid, DimensionHint, auto_grid_with_hint};
+///
+/// let area = Rect::new(0, 0, 100, 90);
+/// let grid = AutoGrid::new().spacing(1).hint(DimensionHint::RowsFirst);
+/// assert_eq!(grid.split(area, 6), auto_grid_with_hint(area, 6, 1, DimensionHint::RowsFirst));
+/// ```
+#[derive(Default)]
+pub struct AutoGrid {
+    spacing: u16,
+    hint: DimensionHint,
+    dimension_fn: Option<Box<DimensionFn>>,
+    stretch_last_row: bool,
+    partial_row_first: bool,
+    balance: Balance,
+    gravity: Gravity,
+    edge_gutters: bool,
+    square_cells: bool,
+    max_cell_size: Option<(u16, u16)>,
+    align_content: (Alignment, Alignment),
+}
+
+impl AutoGrid {
+    /// Creates a grid configuration with no spacing and the default heuristic.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the space between cells.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the heuristic used to pick the dimensions of the grid.
+    ///
+    /// This has no effect once [`AutoGrid::dimension_fn`] is set.
+    pub fn hint(mut self, hint: DimensionHint) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    /// Replaces the heuristic with a closure computing the `(rows, cols)` of the grid from the
+    /// number of items and the area.
+    ///
+    /// The closure must return dimensions whose product is at least the number of items. If it
+    /// doesn't, the output is clamped to the slots of the grid: the items past the last slot come
+    /// back as zero-size rects at the origin of the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::AutoGrid;
+    ///
+    /// // As many columns as fit cells of at least 20 wide.
+    /// let grid = AutoGrid::new().dimension_fn(|n, area| {
+    ///     let cols = (area.width / 20).max(1);
+    ///     (n.div_ceil(usize::from(cols)) as u16, cols)
+    /// });
+    /// let cells = grid.split(Rect::new(0, 0, 60, 20), 4);
+    /// assert_eq!(cells[2], Rect::new(40, 0, 20, 10));
+    /// assert_eq!(cells[3], Rect::new(0, 10, 20, 10));
+    /// ```
+    pub fn dimension_fn(
+        mut self,
+        dimension_fn: impl Fn(usize, Rect) -> (u16, u16) + 'static,
+    ) -> Self {
+        self.dimension_fn = Some(Box::new(dimension_fn));
+        self
+    }
+
+    /// Sets whether the cells of a partial last row are stretched to the full width of the area.
+    ///
+    /// The stretched cells keep the same spacing between them as the full rows, and the last one
+    /// ends on the same edge as the full rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::AutoGrid;
+    ///
+    /// let cells = AutoGrid::new().stretch_last_row(true).split(Rect::new(0, 0, 60, 20), 5);
+    /// assert_eq!(cells[2], Rect::new(40, 0, 20, 10));
+    /// assert_eq!(cells[3], Rect::new(0, 10, 30, 10));
+    /// assert_eq!(cells[4], Rect::new(30, 10, 30, 10));
+    /// ```
+    pub fn stretch_last_row(mut self, stretch: bool) -> Self {
+        self.stretch_last_row = stretch;
+        self
+    }
+
+    /// Sets whether a partial row goes at the top of the grid instead of the bottom.
+    ///
+    /// The first items fill the partial row, and the rest fill the full rows below it in row-major
+    /// order. [`AutoGrid::gravity`] and [`AutoGrid::stretch_last_row`] then apply to that first
+    /// row. This has no effect with [`Balance::Rows`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::{AutoGrid, Gravity};
+    ///
+    /// let grid = AutoGrid::new().partial_row_first(true).gravity(Gravity::Right);
+    /// let cells = grid.split(Rect::new(0, 0, 60, 20), 5);
+    /// assert_eq!(cells[0], Rect::new(20, 0, 20, 10));
+    /// assert_eq!(cells[1], Rect::new(40, 0, 20, 10));
+    /// assert_eq!(cells[2], Rect::new(0, 10, 20, 10));
+    /// ```
+    pub fn partial_row_first(mut self, partial_row_first: bool) -> Self {
+        self.partial_row_first = partial_row_first;
+        self
+    }
+
+    /// Sets where the cells of a partial last row are placed horizontally.
+    ///
+    /// This has no effect when [`AutoGrid::stretch_last_row`] is set, since the stretched cells
+    /// already span the full width, nor with [`Balance::Rows`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::{AutoGrid, Gravity};
+    ///
+    /// let cells = AutoGrid::new().gravity(Gravity::Right).split(Rect::new(0, 0, 60, 20), 5);
+    /// assert_eq!(cells[3], Rect::new(20, 10, 20, 10));
+    /// assert_eq!(cells[4], Rect::new(40, 10, 20, 10));
+    /// ```
+    pub fn gravity(mut self, gravity: Gravity) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets how the items are distributed between the rows.
+    ///
+    /// With [`Balance::Rows`], every row already spans the full width, so
+    /// [`AutoGrid::stretch_last_row`] has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::{AutoGrid, Balance};
+    ///
+    /// let cells = AutoGrid::new().balance(Balance::Rows).split(Rect::new(0, 0, 60, 30), 7);
+    /// // Rows of 3, 2 and 2 cells.
+    /// assert_eq!(cells[2], Rect::new(40, 0, 20, 10));
+    /// assert_eq!(cells[3], Rect::new(0, 10, 30, 10));
+    /// assert_eq!(cells[6], Rect::new(30, 20, 30, 10));
+    /// ```
+    pub fn balance(mut self, balance: Balance) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    /// Sets whether the spacing is also kept between the outer cells and the edges of the area.
+    ///
+    /// The cells are then laid out in the area shrunk by the spacing on all four sides, which
+    /// follows any later change to [`AutoGrid::spacing`]. If the area is too small for the gutters,
+    /// every cell is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::AutoGrid;
+    ///
+    /// let cells = AutoGrid::new().spacing(2).edge_gutters(true).split(Rect::new(0, 0, 32, 14), 2);
+    /// assert_eq!(cells, [Rect::new(2, 2, 13, 10), Rect::new(17, 2, 13, 10)]);
+    /// ```
+    pub fn edge_gutters(mut self, edge_gutters: bool) -> Self {
+        self.edge_gutters = edge_gutters;
+        self
+    }
+
+    /// Caps the width and height of every cell.
+    ///
+    /// When the capped cells don't fill the area, the block of cells is placed within it according
+    /// to [`AutoGrid::align_content`], at the top-left by default. The spacing between cells is
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::AutoGrid;
+    ///
+    /// let cells = AutoGrid::new().spacing(1).max_cell_size(10, 5).split(Rect::new(0, 0, 100, 40), 4);
+    /// assert_eq!(cells[1], Rect::new(11, 0, 10, 5));
+    /// assert_eq!(cells[3], Rect::new(11, 6, 10, 5));
+    /// ```
+    pub fn max_cell_size(mut self, width: u16, height: u16) -> Self {
+        self.max_cell_size = Some((width, height));
+        self
+    }
+
+    /// Sets whether every cell is shrunk to a square.
+    ///
+    /// The side of the squares is the smallest width or height of the cells of the grid, after
+    /// [`AutoGrid::max_cell_size`], so that all the cells are identical. The block of squares is
+    /// placed according to [`AutoGrid::align_content`]. The cells of a partial last row are still
+    /// widened by [`AutoGrid::stretch_last_row`] and [`Balance::Rows`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::{Alignment, Rect};
+    /// use ratatui_auto_grid::AutoGrid;
+    ///
+    /// let cells = AutoGrid::new()
+    ///     .square_cells(true)
+    ///     .align_content(Alignment::Center, Alignment::Left)
+    ///     .split(Rect::new(0, 0, 50, 10), 4);
+    /// assert_eq!(cells[0], Rect::new(20, 0, 5, 5));
+    /// assert_eq!(cells[3], Rect::new(25, 5, 5, 5));
+    /// ```
+    pub fn square_cells(mut self, square_cells: bool) -> Self {
+        self.square_cells = square_cells;
+        self
+    }
+
+    /// Sets where the block of cells is placed when it is smaller than the area.
+    ///
+    /// `vertical` uses [`Alignment::Left`] for the top and [`Alignment::Right`] for the bottom.
+    /// Every cell is shifted by the same offset, so the cells keep their relative positions. This
+    /// has no effect when the cells fill the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::{Alignment, Rect};
+    /// use ratatui_auto_grid::AutoGrid;
+    ///
+    /// let cells = AutoGrid::new()
+    ///     .max_cell_size(10, 5)
+    ///     .align_content(Alignment::Right, Alignment::Center)
+    ///     .split(Rect::new(0, 0, 100, 40), 2);
+    /// assert_eq!(cells, [Rect::new(80, 17, 10, 5), Rect::new(90, 17, 10, 5)]);
+    /// ```
+    pub fn align_content(mut self, horizontal: Alignment, vertical: Alignment) -> Self {
+        self.align_content = (horizontal, vertical);
+        self
+    }
+
+    /// Sets where the cells are placed vertically when they are shorter than the area.
+    ///
+    /// This is the vertical alignment of [`AutoGrid::align_content`], so the last of the two
+    /// calls wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::{AutoGrid, VGravity};
+    ///
+    /// let cells = AutoGrid::new()
+    ///     .max_cell_size(10, 5)
+    ///     .vertical_gravity(VGravity::Bottom)
+    ///     .split(Rect::new(0, 0, 20, 20), 2);
+    /// assert_eq!(cells, [Rect::new(0, 15, 10, 5), Rect::new(10, 15, 10, 5)]);
+    /// ```
+    pub fn vertical_gravity(mut self, gravity: VGravity) -> Self {
+        self.align_content.1 = gravity.into();
+        self
+    }
+
+    /// Returns the `(rows, cols)` of the grid of `n` items in `area`.
+    pub fn dimensions(&self, area: Rect, n: usize) -> (u16, u16) {
+        match &self.dimension_fn {
+            Some(dimension_fn) => dimension_fn(n, area),
+            None => grid_shape(n, self.hint),
+        }
+    }
+
+    /// Arranges `n` items in `area`, returning exactly `n` cells in row-major order.
+    pub fn split(&self, area: Rect, n: usize) -> Vec<Rect> {
+        let (rows, cols) = self.dimensions(area, n);
+        let inner = if self.edge_gutters {
+            area.inner(Margin::new(self.spacing, self.spacing))
+        } else {
+            area
+        };
+        let block = self.content_area(inner, (rows, cols));
+        let mut out = match self.balance {
+            Balance::Columns => {
+                let mut out = grid_cells(block, n, (rows, cols), self.spacing, self.spacing);
+                if self.partial_row_first {
+                    move_partial_row_first(block, &mut out, (rows, cols));
+                }
+                if self.stretch_last_row {
+                    self.stretch(block, &mut out, (rows, cols));
+                } else if self.gravity != Gravity::Left {
+                    self.apply_gravity(block, &mut out, (rows, cols));
+                }
+                out
+            }
+            Balance::Rows => self.balanced_rows(block, n, (rows, cols)),
+        };
+        collapse_empty(area, &mut out);
+        out
+    }
+
+    /// Pairs each item of `items` with its cell of [`AutoGrid::split`] for `items.len()` items.
+    ///
+    /// The pairs come in the order of the cells, so the items follow whichever fill the options
+    /// configure, like the rows of [`Balance::Rows`].
+    pub fn zip<'a, T>(
+        &self,
+        area: Rect,
+        items: &'a [T],
+    ) -> impl ExactSizeIterator<Item = (&'a T, Rect)> + use<'a, T> {
+        items.iter().zip(self.split(area, items.len()))
+    }
+
+    /// Pairs each item of `items`, mutably, with its cell of [`AutoGrid::split`].
+    pub fn zip_mut<'a, T>(
+        &self,
+        area: Rect,
+        items: &'a mut [T],
+    ) -> impl ExactSizeIterator<Item = (&'a mut T, Rect)> + use<'a, T> {
+        let cells = self.split(area, items.len());
+        items.iter_mut().zip(cells)
+    }
+
+    /// Returns the part of `area` covered by the cells, once capped, squared and aligned.
+    fn content_area(&self, area: Rect, (rows, cols): (u16, u16)) -> Rect {
+        let (mut max_width, mut max_height) = match self.max_cell_size {
+            Some(max) => max,
+            None if self.square_cells => (u16::MAX, u16::MAX),
+            None => return area,
+        };
+        if self.square_cells {
+            let side = smallest_track(area.width, cols, self.spacing)
+                .min(smallest_track(area.height, rows, self.spacing))
+                .min(max_width)
+                .min(max_height);
+            (max_width, max_height) = (side, side);
+        }
+
+        let width = capped_length(area.width, cols, self.spacing, max_width);
+        let height = capped_length(area.height, rows, self.spacing, max_height);
+        let (horizontal, vertical) = self.align_content;
+        new_rect(
+            area.x + offset(horizontal, area.width - width),
+            area.y + offset(vertical, area.height - height),
+            width,
+            height,
+        )
+    }
+
+    /// Lays out `n` items in `rows` rows whose lengths differ by at most one.
+    ///
+    /// Like the default balance, only the `rows * cols` slots of the grid get a cell.
+    fn balanced_rows(&self, area: Rect, n: usize, (rows, cols): (u16, u16)) -> Vec<Rect> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let slots = n.min(usize::from(rows) * usize::from(cols));
+        let mut out = Vec::with_capacity(n);
+        if rows > 0 {
+            let (base, extra) = (slots / usize::from(rows), slots % usize::from(rows));
+            for (row, (y, height)) in
+                split_axis(area.y, area.height, rows, self.spacing).enumerate()
+            {
+                let count = base + usize::from(row < extra);
+                let columns = split_axis(area.x, area.width, count as u16, self.spacing);
+                out.extend(columns.map(|(x, width)| new_rect(x, y, width, height)));
+            }
+        }
+        out.resize(n, empty_cell(area));
+        collapse_empty(area, &mut out);
+        out
+    }
+
+    /// Stretches the cells of a partial row to the full width of `area`.
+    fn stretch(&self, area: Rect, cells: &mut [Rect], dimensions: (u16, u16)) {
+        let Some(range) = self.partial_range(cells, dimensions) else {
+            return;
+        };
+
+        let row = &mut cells[range];
+        let first = row[0];
+        let columns = split_axis(area.x, area.width, row.len() as u16, self.spacing);
+        for (cell, (x, width)) in row.iter_mut().zip(columns) {
+            *cell = Rect { x, width, ..first };
+        }
+        collapse_empty(area, row);
+    }
+
+    /// Returns the indices of the cells of the partial row, at the top or the bottom of the grid,
+    /// if it has room.
+    fn partial_range(&self, cells: &[Rect], dimensions: (u16, u16)) -> Option<Range<usize>> {
+        let partial = partial_len(cells.len(), dimensions)?;
+        let range = if self.partial_row_first {
+            0..partial
+        } else {
+            cells.len() - partial..cells.len()
+        };
+        (!cells[range.start].is_empty()).then_some(range)
+    }
+
+    /// Moves the cells of a partial row within the width of `area` according to the gravity.
+    fn apply_gravity(&self, area: Rect, cells: &mut [Rect], dimensions: (u16, u16)) {
+        let Some(range) = self.partial_range(cells, dimensions) else {
+            return;
+        };
+
+        let row = &mut cells[range];
+        let end = row
+            .iter()
+            .filter(|cell| !cell.is_empty())
+            .map(|cell| cell.right())
+            .max();
+        let free = area.right() - end.unwrap_or(area.right());
+        match self.gravity {
+            Gravity::Left => {}
+            Gravity::Center => row.iter_mut().for_each(|cell| cell.x += free / 2),
+            Gravity::Right => row.iter_mut().for_each(|cell| cell.x += free),
+            Gravity::Justify if row.len() > 1 => {
+                let widths: u16 = row.iter().map(|cell| cell.width).sum();
+                let gaps = row.len() as u16 - 1;
+                let gutters = area.width - widths;
+                let mut x = area.x;
+                for (i, cell) in row.iter_mut().enumerate() {
+                    cell.x = x;
+                    x += cell.width + gutters / gaps + u16::from((i as u16) < gutters % gaps);
+                }
+            }
+            Gravity::Justify => {}
+        }
+        collapse_empty(area, row);
+    }
+}
+
+/// Returns the number of cells of the partial row of a grid of `len` cells, if there is one.
+fn partial_len(len: usize, (rows, cols): (u16, u16)) -> Option<usize> {
+    let cols = usize::from(cols);
+    if cols == 0 || len > usize::from(rows) * cols {
+        return None;
+    }
+    Some(len % cols).filter(|&partial| partial > 0)
+}
+
+/// Moves the partial last row of `cells` to the top of the grid, the full rows moving down by one.
+fn move_partial_row_first(area: Rect, cells: &mut [Rect], dimensions: (u16, u16)) {
+    let cols = usize::from(dimensions.1);
+    let Some(partial) = partial_len(cells.len(), dimensions).filter(|_| cells.len() > cols) else {
+        return;
+    };
+
+    // Every slot before the partial row has a cell, so the tracks can be read from them.
+    let grid = cells.to_vec();
+    let slot = |k: usize| Rect {
+        y: grid[k / cols * cols].y,
+        height: grid[k / cols * cols].height,
+        ..grid[k % cols]
+    };
+    for (i, cell) in cells.iter_mut().enumerate() {
+        *cell = slot(if i < partial { i } else { i - partial + cols });
+    }
+    collapse_empty(area, cells);
+}
+
+/// Returns the length taken by `tracks` tracks of at most `max` separated by `spacing`, within
+/// `length`.
+fn capped_length(length: u16, tracks: u16, spacing: u16, max: u16) -> u16 {
+    if tracks == 0 {
+        return length;
+    }
+    let capped = u32::from(tracks) * u32::from(max) + u32::from(tracks - 1) * u32::from(spacing);
+    capped.min(u32::from(length)) as u16
+}
+
+/// Returns the length of the shortest of `tracks` tracks separated by `spacing` in `length`.
+fn smallest_track(length: u16, tracks: u16, spacing: u16) -> u16 {
+    if tracks == 0 {
+        return u16::MAX;
+    }
+    let spacing = u32::from(tracks - 1) * u32::from(spacing);
+    (u32::from(length).saturating_sub(spacing) / u32::from(tracks)) as u16
+}
+
+/// Returns the offset of a block within `free` units of free space.
+fn offset(alignment: Alignment, free: u16) -> u16 {
+    match alignment {
+        Alignment::Left => 0,
+        Alignment::Center => free / 2,
+        Alignment::Right => free,
+    }
+}
+
+impl fmt::Debug for AutoGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoGrid")
+            .field("spacing", &self.spacing)
+            .field("hint", &self.hint)
+            .field("dimension_fn", &self.dimension_fn.as_ref().map(|_| "Fn"))
+            .field("stretch_last_row", &self.stretch_last_row)
+            .field("partial_row_first", &self.partial_row_first)
+            .field("balance", &self.balance)
+            .field("gravity", &self.gravity)
+            .field("edge_gutters", &self.edge_gutters)
+            .field("square_cells", &self.square_cells)
+            .field("max_cell_size", &self.max_cell_size)
+            .field("align_content", &self.align_content)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[test]
+    fn defaults_match_auto_grid() {
+        let area = Rect::new(3, 7, 91, 53);
+        for n in [0, 1, 5, 9, 17] {
+            assert_eq!(
+                AutoGrid::new().spacing(2).split(area, n),
+                auto_grid(area, n, 2)
+            );
+        }
+    }
+
+    #[test]
+    fn dimension_fn_forces_a_single_row() {
+        let area = Rect::new(0, 0, 50, 10);
+        let grid = AutoGrid::new().dimension_fn(|n, _| (1, n as u16));
+        let cells = grid.split(area, 5);
+
+        assert_eq!(grid.dimensions(area, 5), (1, 5));
+        assert_eq!(cells.len(), 5);
+        for (i, cell) in cells.iter().enumerate() {
+            assert_eq!(*cell, Rect::new(i as u16 * 10, 0, 10, 10));
+        }
+    }
+
+    #[test]
+    fn dimension_fn_too_small_is_clamped() {
+        let area = Rect::new(5, 5, 40, 10);
+        let cells = AutoGrid::new().dimension_fn(|_, _| (1, 2)).split(area, 4);
+
+        assert_eq!(
+            cells[..2],
+            [Rect::new(5, 5, 20, 10), Rect::new(25, 5, 20, 10)]
+        );
+        assert_eq!(cells[2..], [Rect::new(5, 5, 0, 0); 2]);
+
+        let cells = AutoGrid::new().dimension_fn(|_, _| (0, 0)).split(area, 3);
+        assert_eq!(cells, [Rect::new(5, 5, 0, 0); 3]);
+    }
+
+    #[test]
+    fn stretched_last_row_keeps_the_spacing() {
+        let area = Rect::new(2, 3, 61, 31);
+        let cells = AutoGrid::new()
+            .spacing(2)
+            .stretch_last_row(true)
+            .split(area, 5);
+
+        assert_eq!(cells[..3], auto_grid(area, 5, 2)[..3]);
+        let full_gutters: Vec<u16> = cells[..3]
+            .windows(2)
+            .map(|w| w[1].x - w[0].right())
+            .collect();
+        assert_eq!(full_gutters, [2, 2]);
+        assert_eq!(cells[4].x - cells[3].right(), 2);
+
+        assert_eq!(
+            (cells[3].x, cells[4].right()),
+            (cells[0].x, cells[2].right())
+        );
+        assert_eq!(cells[3].y, cells[0].bottom() + 2);
+        assert_eq!(cells[3].height, cells[4].height);
+    }
+
+    #[test]
+    fn stretching_full_or_clamped_grids_changes_nothing() {
+        let area = Rect::new(0, 0, 40, 10);
+        let stretched = AutoGrid::new().stretch_last_row(true);
+        assert_eq!(stretched.split(area, 9), auto_grid(area, 9, 0));
+
+        let clamped = stretched.dimension_fn(|_, _| (1, 2));
+        assert_eq!(
+            clamped.split(area, 3),
+            AutoGrid::new().dimension_fn(|_, _| (1, 2)).split(area, 3)
+        );
+    }
+
+    /// Returns the number of cells in each row of `cells`.
+    fn row_lengths(cells: &[Rect]) -> Vec<usize> {
+        cells
+            .chunk_by(|a, b| a.y == b.y)
+            .map(<[Rect]>::len)
+            .collect()
+    }
+
+    #[test]
+    fn balanced_row_lengths() {
+        let area = Rect::new(2, 3, 61, 31);
+        let grid = AutoGrid::new().spacing(1).balance(Balance::Rows);
+        for (n, expected) in [(7, [3, 2, 2]), (10, [4, 3, 3]), (11, [4, 4, 3])] {
+            let cells = grid.split(area, n);
+            assert_eq!(cells.len(), n);
+            assert_eq!(row_lengths(&cells), expected, "n {n}");
+
+            // Row-major, and every row spans the width.
+            for pair in cells.windows(2) {
+                assert!((pair[0].y, pair[0].x) < (pair[1].y, pair[1].x));
+                if pair[0].y != pair[1].y {
+                    assert_eq!(pair[0].right(), area.right());
+                    assert_eq!(pair[1].x, area.x);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn balanced_rows_match_full_grids() {
+        let area = Rect::new(2, 3, 61, 31);
+        let grid = AutoGrid::new().spacing(2).balance(Balance::Rows);
+        for n in [0, 1, 4, 6, 9, 12] {
+            assert_eq!(grid.split(area, n), auto_grid(area, n, 2), "n {n}");
+        }
+
+        let clamped = AutoGrid::new()
+            .balance(Balance::Rows)
+            .dimension_fn(|_, _| (2, 2));
+        let cells = clamped.split(Rect::new(0, 0, 10, 10), 5);
+        assert_eq!(cells[3], Rect::new(5, 5, 5, 5));
+        assert_eq!(cells[4], Rect::ZERO);
+    }
+
+    #[test]
+    fn zip_follows_the_configured_fill() {
+        let area = Rect::new(0, 0, 60, 30);
+        let grid = AutoGrid::new().spacing(1).balance(Balance::Rows);
+        let mut items: Vec<usize> = (0..7).collect();
+        let cells = grid.split(area, 7);
+
+        let pairs: Vec<_> = grid.zip(area, &items).map(|(&i, cell)| (i, cell)).collect();
+        assert_eq!(pairs, cells.iter().copied().enumerate().collect::<Vec<_>>());
+
+        let zipped = grid.zip_mut(area, &mut items);
+        assert_eq!(zipped.len(), 7);
+        for (item, cell) in zipped {
+            *item = usize::from(cell.y);
+        }
+        assert_eq!(items[6], usize::from(cells[6].y));
+    }
+
+    #[test]
+    fn centered_content_has_symmetric_margins() {
+        let area = Rect::new(0, 0, 100, 40);
+        let cells = AutoGrid::new()
+            .max_cell_size(10, 5)
+            .align_content(Alignment::Center, Alignment::Center)
+            .split(area, 4);
+
+        assert_eq!(
+            cells,
+            [
+                Rect::new(40, 15, 10, 5),
+                Rect::new(50, 15, 10, 5),
+                Rect::new(40, 20, 10, 5),
+                Rect::new(50, 20, 10, 5),
+            ]
+        );
+        let bounds = cells.iter().fold(cells[0], |acc, cell| acc.union(*cell));
+        assert_eq!(bounds.x - area.x, area.right() - bounds.right());
+        assert_eq!(bounds.y - area.y, area.bottom() - bounds.bottom());
+    }
+
+    #[test]
+    fn aligned_content_keeps_relative_positions() {
+        let area = Rect::new(3, 4, 70, 33);
+        let top_left = AutoGrid::new().spacing(1).max_cell_size(12, 7);
+        for n in [1, 5, 9] {
+            let reference = top_left.split(area, n);
+            for (horizontal, vertical) in [
+                (Alignment::Center, Alignment::Left),
+                (Alignment::Right, Alignment::Right),
+            ] {
+                let grid = AutoGrid::new()
+                    .spacing(1)
+                    .max_cell_size(12, 7)
+                    .align_content(horizontal, vertical);
+                let cells = grid.split(area, n);
+                let (dx, dy) = (cells[0].x - reference[0].x, cells[0].y - reference[0].y);
+                for (cell, reference) in cells.iter().zip(&reference) {
+                    assert_eq!(
+                        *cell,
+                        Rect {
+                            x: reference.x + dx,
+                            y: reference.y + dy,
+                            ..*reference
+                        }
+                    );
+                    assert_eq!(cell.intersection(area), *cell);
+                }
+            }
+        }
+
+        // Cells that already fill the area don't move.
+        let full = AutoGrid::new().align_content(Alignment::Right, Alignment::Right);
+        assert_eq!(full.split(area, 5), auto_grid(area, 5, 0));
+    }
+
+    #[test]
+    fn right_gravity_ends_on_the_right_edge() {
+        let area = Rect::new(5, 2, 62, 31);
+        let grid = AutoGrid::new().spacing(1).gravity(Gravity::Right);
+        for n in [5, 7, 10, 11] {
+            let cells = grid.split(area, n);
+            let full = auto_grid(area, n, 1);
+            assert_eq!(cells.last().unwrap().right(), area.right(), "n {n}");
+            // The full rows don't move, and the partial row keeps its spacing.
+            let start = n - n % full.iter().filter(|cell| cell.y == area.y).count();
+            assert_eq!(cells[..start], full[..start]);
+            for pair in cells[start..].windows(2) {
+                assert_eq!(pair[1].x, pair[0].right() + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn partial_row_first_is_on_top() {
+        let area = Rect::new(0, 0, 90, 20);
+        let cells = AutoGrid::new().partial_row_first(true).split(area, 5);
+        let full = auto_grid(area, 6, 0);
+
+        // 2 cells on the top row, then 3 on the bottom row.
+        assert_eq!(cells[..2], full[..2]);
+        assert_eq!(cells[2..], full[3..]);
+        assert_eq!(cells.iter().filter(|cell| cell.y == 0).count(), 2);
+        assert_eq!(cells.iter().filter(|cell| cell.y == 10).count(), 3);
+
+        // Full grids don't change.
+        let grid = AutoGrid::new().spacing(1).partial_row_first(true);
+        assert_eq!(grid.split(area, 6), auto_grid(area, 6, 1));
+        assert_eq!(grid.split(area, 1), auto_grid(area, 1, 1));
+    }
+
+    #[test]
+    fn partial_row_first_with_gravity_and_stretch() {
+        let area = Rect::new(5, 2, 62, 31);
+        let grid = AutoGrid::new()
+            .spacing(1)
+            .partial_row_first(true)
+            .gravity(Gravity::Right);
+        let cells = grid.split(area, 7);
+        let full = auto_grid(area, 9, 1);
+        assert_eq!(cells[0], full[2]);
+        assert_eq!(cells[1..], full[3..]);
+
+        let cells = grid.stretch_last_row(true).split(area, 8);
+        assert_eq!(cells[0].x, area.x);
+        assert_eq!(cells[1].right(), area.right());
+        assert_eq!(cells[1].x, cells[0].right() + 1);
+        assert_eq!(cells[2..], full[3..]);
+    }
+
+    #[test]
+    fn centered_gravity() {
+        let area = Rect::new(0, 0, 90, 20);
+        let cells = AutoGrid::new().gravity(Gravity::Center).split(area, 5);
+        // 3 columns of 30, the 2 last cells leave 30 to share.
+        assert_eq!(cells[3], Rect::new(15, 10, 30, 10));
+        assert_eq!(cells[4], Rect::new(45, 10, 30, 10));
+
+        let cells = AutoGrid::new().gravity(Gravity::Center).split(area, 7);
+        assert_eq!(cells[6], Rect::new(30, 14, 30, 6));
+    }
+
+    #[test]
+    fn justified_gravity() {
+        let area = Rect::new(0, 0, 100, 20);
+        let cells = AutoGrid::new()
+            .dimension_fn(|_, _| (2, 4))
+            .gravity(Gravity::Justify)
+            .split(area, 7);
+        // Widths of 25, with 25 left for the 2 gutters.
+        assert_eq!(cells[4].x, 0);
+        assert_eq!(cells[5].x, 38);
+        assert_eq!(cells[6].x, 75);
+
+        // Full grids and single cells don't move.
+        let grid = AutoGrid::new().gravity(Gravity::Justify);
+        assert_eq!(grid.split(area, 4), auto_grid(area, 4, 0));
+        assert_eq!(grid.split(area, 3), auto_grid(area, 3, 0));
+    }
+
+    #[test]
+    fn edge_gutters_match_the_spacing() {
+        let area = Rect::new(4, 3, 71, 40);
+        for spacing in [0, 1, 3] {
+            let grid = AutoGrid::new().spacing(spacing).edge_gutters(true);
+            for n in [1, 4, 7] {
+                let cells = grid.split(area, n);
+                assert_eq!(cells.len(), n);
+                let bounds = cells.iter().fold(cells[0], |acc, cell| acc.union(*cell));
+                assert_eq!(
+                    bounds,
+                    Rect::new(
+                        area.x + spacing,
+                        area.y + spacing,
+                        area.width - 2 * spacing,
+                        area.height - 2 * spacing
+                    ),
+                    "spacing {spacing}, n {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn edge_gutters_wider_than_the_area() {
+        let area = Rect::new(4, 3, 5, 40);
+        let cells = AutoGrid::new().spacing(3).edge_gutters(true).split(area, 3);
+        assert_eq!(cells, [Rect::new(4, 3, 0, 0); 3]);
+    }
+
+    #[test]
+    fn square_cells_are_identical() {
+        for area in [
+            Rect::new(0, 0, 80, 24),
+            Rect::new(3, 1, 24, 80),
+            Rect::new(7, 9, 41, 41),
+            Rect::new(0, 0, 9, 3),
+        ] {
+            for n in [1, 3, 8, 64] {
+                let grid = AutoGrid::new().spacing(1).square_cells(true);
+                let cells = grid.split(area, n);
+                let side = cells[0].width;
+                for (i, cell) in cells.iter().enumerate() {
+                    assert_eq!((cell.width, cell.height), (side, side), "{area} n {n}");
+                    assert_eq!(cell.intersection(area), *cell);
+                    for other in &cells[i + 1..] {
+                        assert!(cell.is_empty() || !cell.intersects(*other));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn middle_gravity_has_equal_vertical_margins() {
+        let grid = AutoGrid::new()
+            .spacing(1)
+            .square_cells(true)
+            .vertical_gravity(VGravity::Middle);
+        for (area, n) in [(Rect::new(0, 0, 23, 41), 4), (Rect::new(2, 5, 30, 61), 9)] {
+            let cells = grid.split(area, n);
+            let top = cells.iter().map(|cell| cell.y).min().unwrap();
+            let bottom = cells.iter().map(|cell| cell.bottom()).max().unwrap();
+            assert!(top > area.y);
+            assert_eq!(top - area.y, area.bottom() - bottom, "{area}");
+        }
+    }
+}
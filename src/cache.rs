@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use ratatui::layout::Rect;
+
+use crate::auto_grid;
+
+/// A memoization cache for [`auto_grid`] layouts.
+///
+/// Layouts are keyed by `(area, n, spacing)`, so a resize or a change in the number of items is
+/// picked up immediately, while repeated calls with the same inputs (typically every frame) return
+/// a cheap clone of the previous result.
+///
+/// The cache is unbounded by default. A capacity can be set to evict the least recently used layout
+/// once it's full, and a capacity of 0 disables caching entirely.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{GridCache, auto_grid};
+///
+/// let mut cache = GridCache::new();
+/// let area = Rect::new(0, 0, 100, 100);
+///
+/// let cells = cache.get(area, 9, 1);
+/// assert_eq!(*cells, *auto_grid(area, 9, 1));
+///
+/// cache.get(area, 9, 1);
+/// assert_eq!(cache.stats().hits, 1);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct GridCache {
+    entries: HashMap<(Rect, usize, u16), Entry>,
+    capacity: Option<usize>,
+    hits: u64,
+    misses: u64,
+    tick: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    cells: Rc<[Rect]>,
+    last_used: u64,
+}
+
+/// Statistics of a [`GridCache`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheStats {
+    /// Number of lookups answered from the cache.
+    pub hits: u64,
+    /// Number of lookups that had to compute the layout.
+    pub misses: u64,
+    /// Number of layouts currently stored.
+    pub entries: usize,
+}
+
+impl GridCache {
+    /// Creates an empty, unbounded cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty cache holding at most `capacity` layouts.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the maximum number of cached layouts, evicting the least recently used ones if there
+    /// are more.
+    ///
+    /// A capacity of 0 behaves exactly like calling [`auto_grid`] directly.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = Some(capacity);
+        while self.entries.len() > capacity {
+            self.evict();
+        }
+    }
+
+    /// Removes the capacity limit.
+    pub fn set_unbounded(&mut self) {
+        self.capacity = None;
+    }
+
+    /// Disables caching, removing every cached layout. Same as a capacity of 0.
+    pub fn disable(&mut self) {
+        self.set_capacity(0);
+    }
+
+    /// Returns the maximum number of cached layouts, or `None` if the cache is unbounded.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Returns the cells of [`auto_grid`] for these inputs, computing them only if they are not
+    /// cached yet.
+    pub fn get(&mut self, area: Rect, n: usize, spacing: u16) -> Rc<[Rect]> {
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(&(area, n, spacing)) {
+            self.hits += 1;
+            entry.last_used = self.tick;
+            return Rc::clone(&entry.cells);
+        }
+
+        self.misses += 1;
+        let cells: Rc<[Rect]> = auto_grid(area, n, spacing).into();
+        if self.capacity == Some(0) {
+            return cells;
+        }
+
+        if self
+            .capacity
+            .is_some_and(|capacity| self.entries.len() >= capacity)
+        {
+            self.evict();
+        }
+        let entry = Entry {
+            cells: Rc::clone(&cells),
+            last_used: self.tick,
+        };
+        self.entries.insert((area, n, spacing), entry);
+        cells
+    }
+
+    /// Removes the least recently used layout.
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(&key, _)| key);
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+
+    /// Removes every cached layout. The statistics are kept.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the hit and miss counters and the number of cached layouts.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_calls_hit_the_cache() {
+        let mut cache = GridCache::new();
+        let area = Rect::new(0, 0, 100, 100);
+
+        let first = cache.get(area, 9, 1);
+        let second = cache.get(area, 9, 1);
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1
+            }
+        );
+    }
+
+    #[test]
+    fn changed_inputs_miss_the_cache() {
+        let mut cache = GridCache::new();
+        let area = Rect::new(0, 0, 100, 100);
+
+        cache.get(area, 9, 1);
+        cache.get(area, 8, 1);
+        cache.get(area, 9, 0);
+        let resized = cache.get(Rect::new(0, 0, 80, 100), 9, 1);
+
+        assert_eq!(*resized, *auto_grid(Rect::new(0, 0, 80, 100), 9, 1));
+        assert_eq!(cache.stats().misses, 4);
+        assert_eq!(cache.stats().hits, 0);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = GridCache::with_capacity(3);
+        let area = Rect::new(0, 0, 100, 100);
+
+        for n in 1..=4 {
+            cache.get(area, n, 0);
+        }
+        assert_eq!(cache.stats().entries, 3);
+
+        // n = 1 was evicted, n = 2 is still there.
+        cache.get(area, 2, 0);
+        assert_eq!(cache.stats().hits, 1);
+        cache.get(area, 1, 0);
+        assert_eq!(cache.stats().misses, 5);
+
+        // n = 3 was the least recently used.
+        cache.get(area, 4, 0);
+        cache.get(area, 3, 0);
+        assert_eq!(cache.stats().hits, 2);
+    }
+
+    #[test]
+    fn zero_capacity_disables_caching() {
+        let mut cache = GridCache::new();
+        let area = Rect::new(0, 0, 100, 100);
+
+        cache.get(area, 4, 0);
+        cache.disable();
+        assert_eq!(cache.stats().entries, 0);
+
+        let cells = cache.get(area, 4, 0);
+        cache.get(area, 4, 0);
+        assert_eq!(*cells, *auto_grid(area, 4, 0));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 0,
+                misses: 3,
+                entries: 0
+            }
+        );
+    }
+
+    #[test]
+    fn shrinking_capacity_evicts() {
+        let mut cache = GridCache::new();
+        let area = Rect::new(0, 0, 100, 100);
+
+        for n in 1..=5 {
+            cache.get(area, n, 0);
+        }
+        cache.set_capacity(2);
+        assert_eq!(cache.stats().entries, 2);
+        assert_eq!(cache.capacity(), Some(2));
+
+        cache.get(area, 5, 0);
+        assert_eq!(cache.stats().hits, 1);
+    }
+
+    #[test]
+    fn clear_forces_a_recomputation() {
+        let mut cache = GridCache::new();
+        let area = Rect::new(0, 0, 100, 100);
+
+        cache.get(area, 4, 0);
+        cache.clear();
+        assert_eq!(cache.stats().entries, 0);
+
+        cache.get(area, 4, 0);
+        assert_eq!(cache.stats().misses, 2);
+    }
+}
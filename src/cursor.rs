@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+use crate::auto_grid;
+
+/// Hands out the cells of an [`auto_grid`] layout one at a time, for items that arrive
+/// incrementally.
+///
+/// The grid is computed once for the expected maximum number of items, and each call to
+/// [`GridCursor::next`] returns the next cell in row-major order, until all `max_n` cells have been
+/// handed out.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::GridCursor;
+///
+/// let mut cursor = GridCursor::new(Rect::new(0, 0, 20, 10), 4, 0);
+/// assert_eq!(cursor.next(), Some(Rect::new(0, 0, 10, 5)));
+/// assert_eq!(cursor.next(), Some(Rect::new(10, 0, 10, 5)));
+/// assert_eq!(cursor.position(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridCursor {
+    cells: Vec<Rect>,
+    position: usize,
+}
+
+impl GridCursor {
+    /// Lays out a grid of `max_n` items in `area`, ready to hand out its first cell.
+    pub fn new(area: Rect, max_n: usize, spacing: u16) -> Self {
+        Self {
+            cells: auto_grid(area, max_n, spacing),
+            position: 0,
+        }
+    }
+
+    /// Returns the number of cells handed out so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Starts handing out the cells from the first one again.
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+}
+
+impl Iterator for GridCursor {
+    type Item = Rect;
+
+    fn next(&mut self) -> Option<Rect> {
+        let cell = *self.cells.get(self.position)?;
+        self.position += 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cells.len() - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for GridCursor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproduces_auto_grid_in_order() {
+        let area = Rect::new(3, 2, 97, 41);
+        for n in [0, 1, 7, 16] {
+            let mut cursor = GridCursor::new(area, n, 1);
+            let cells: Vec<Rect> = (0..n).map(|_| cursor.next().unwrap()).collect();
+            assert_eq!(cells, auto_grid(area, n, 1));
+            assert_eq!(cursor.next(), None);
+        }
+    }
+
+    #[test]
+    fn reset_starts_over() {
+        let mut cursor = GridCursor::new(Rect::new(0, 0, 30, 30), 5, 0);
+        let first = cursor.next();
+        cursor.by_ref().take(2).for_each(drop);
+        assert_eq!(cursor.len(), 2);
+
+        cursor.reset();
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.next(), first);
+    }
+}
@@ -0,0 +1,308 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+use ratatui::style::Style;
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+use ratatui::widgets::{Block, Widget};
+
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+use crate::auto_grid;
+
+/// Widest diagram drawn by [`render_ascii`], in characters.
+const MAX_DIAGRAM_WIDTH: u16 = 80;
+
+/// Draws `cells` as an ASCII diagram of `area`, for debugging and golden tests.
+///
+/// Each cell is drawn as a `+--+` box labeled with its index, and the spacing between cells is
+/// left blank. Areas wider than 80 columns are scaled down by the same factor on both axes so
+/// that the diagram stays at most 80 characters wide. Cells too small to hold a label after
+/// scaling are drawn without one.
+///
+/// Zero-size cells are drawn as a `*` at their position, and their indices are listed on a last
+/// `zero-size:` line since several of them usually share the same position.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, render_ascii};
+///
+/// let area = Rect::new(0, 0, 13, 7);
+/// let diagram = render_ascii(&auto_grid(area, 3, 1), area);
+/// assert_eq!(
+///     diagram,
+///     "\
+/// +----+ +----+
+/// |0   | |1   |
+/// +----+ +----+
+///
+/// +----+
+/// |2   |
+/// +----+
+/// "
+/// );
+/// ```
+pub fn render_ascii(cells: &[Rect], area: Rect) -> String {
+    let scale = area.width.div_ceil(MAX_DIAGRAM_WIDTH).max(1);
+    draw(cells, area, scale, Label::Inside)
+}
+
+/// Draws `cells` at the scale of `area`, one character per cell, for snapshot tests.
+///
+/// This is the same diagram as [`render_ascii`], without scaling and with the index of each cell
+/// written over its top-left corner, so that the box of every cell shows its exact position and
+/// size.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, debug_render};
+///
+/// let area = Rect::new(0, 0, 8, 3);
+/// assert_eq!(debug_render(area, &auto_grid(area, 2, 0)), "0--+1--+\n|  ||  |\n+--++--+\n");
+/// ```
+pub fn debug_render(area: Rect, cells: &[Rect]) -> String {
+    draw(cells, area, 1, Label::Corner)
+}
+
+/// A widget drawing the cells of an automatic grid layout with their indices, for debugging.
+///
+/// The grid is computed with [`auto_grid`] over the area the widget is rendered in. Each cell is
+/// drawn as a bordered block with its index centered inside. Zero-size cells are not drawn.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Widget;
+/// use ratatui_auto_grid::GridDebugWidget;
+///
+/// let area = Rect::new(0, 0, 10, 3);
+/// let mut buf = Buffer::empty(area);
+/// GridDebugWidget::new(2, 0).render(area, &mut buf);
+/// assert_eq!(
+///     buf,
+///     Buffer::with_lines(["┌───┐┌───┐", "│ 0 ││ 1 │", "└───┘└───┘"])
+/// );
+/// ```
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridDebugWidget {
+    n: usize,
+    spacing: u16,
+}
+
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+impl GridDebugWidget {
+    /// Creates a widget drawing a grid of `n` cells with `spacing` between them.
+    pub fn new(n: usize, spacing: u16) -> Self {
+        Self { n, spacing }
+    }
+}
+
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+impl Widget for GridDebugWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for (index, cell) in auto_grid(area, self.n, self.spacing)
+            .into_iter()
+            .enumerate()
+        {
+            if cell.is_empty() {
+                continue;
+            }
+
+            Block::bordered().render(cell, buf);
+            let label = index.to_string();
+            let width = (label.len() as u16).min(cell.width);
+            let x = cell.x + (cell.width - width) / 2;
+            let y = cell.y + cell.height.saturating_sub(1) / 2;
+            buf.set_stringn(x, y, &label, usize::from(width), Style::new());
+        }
+    }
+}
+
+/// Where the index of a cell goes in its box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Label {
+    /// Inside the box, when there is room for it.
+    Inside,
+    /// Over the top-left corner.
+    Corner,
+}
+
+/// Draws the boxes of `cells` on a canvas covering `area` scaled down by `scale`.
+fn draw(cells: &[Rect], area: Rect, scale: u16, label: Label) -> String {
+    let width = usize::from(area.width.div_ceil(scale));
+    let height = usize::from(area.height.div_ceil(scale));
+    let mut canvas = vec![vec![' '; width]; height];
+
+    // Maps a position on one axis to a canvas index, relative to `origin`.
+    let map = |position: u16, origin: u16| usize::from(position.saturating_sub(origin) / scale);
+
+    let mut zero_size = Vec::new();
+    for (index, cell) in cells.iter().enumerate() {
+        let (left, top) = (map(cell.x, area.x), map(cell.y, area.y));
+        if cell.is_empty() {
+            zero_size.push(index.to_string());
+            if let Some(slot) = canvas.get_mut(top).and_then(|row| row.get_mut(left)) {
+                *slot = '*';
+            }
+            continue;
+        }
+
+        // The far edges are mapped exclusively so that adjacent cells don't share a border.
+        let right = map(cell.right(), area.x).saturating_sub(1).max(left);
+        let bottom = map(cell.bottom(), area.y).saturating_sub(1).max(top);
+        if left >= width || top >= height {
+            continue;
+        }
+        let (right, bottom) = (right.min(width - 1), bottom.min(height - 1));
+
+        for (y, row) in canvas.iter_mut().enumerate().take(bottom + 1).skip(top) {
+            for (x, slot) in row.iter_mut().enumerate().take(right + 1).skip(left) {
+                let horizontal = y == top || y == bottom;
+                let vertical = x == left || x == right;
+                *slot = match (horizontal, vertical) {
+                    (true, true) => '+',
+                    (true, false) => '-',
+                    (false, true) => '|',
+                    (false, false) => ' ',
+                };
+            }
+        }
+
+        let slots = match label {
+            Label::Inside if bottom > top + 1 && right > left + 1 => {
+                &mut canvas[top + 1][left + 1..right]
+            }
+            Label::Inside => continue,
+            Label::Corner => &mut canvas[top][left..=right],
+        };
+        for (slot, digit) in slots.iter_mut().zip(index.to_string().chars()) {
+            *slot = digit;
+        }
+    }
+
+    let mut out = String::new();
+    for row in canvas {
+        let line: String = row.into_iter().collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    if !zero_size.is_empty() {
+        out.push_str("zero-size: ");
+        out.push_str(&zero_size.join(", "));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[test]
+    fn ragged_last_row() {
+        let area = Rect::new(10, 10, 17, 7);
+        let diagram = render_ascii(&auto_grid(area, 5, 1), area);
+        assert_eq!(
+            diagram,
+            "\
++---+ +---+ +---+
+|0  | |1  | |2  |
++---+ +---+ +---+
+
++---+ +---+
+|3  | |4  |
++---+ +---+
+"
+        );
+    }
+
+    #[test]
+    fn zero_size_cells() {
+        let area = Rect::new(0, 0, 5, 3);
+        let diagram = render_ascii(&auto_grid(area, 9, 2), area);
+        assert_eq!(diagram, "*\n\n\nzero-size: 0, 1, 2, 3, 4, 5, 6, 7, 8\n");
+        assert_eq!(render_ascii(&[Rect::new(0, 0, 5, 3)], Rect::ZERO), "");
+
+        let cells = [Rect::new(0, 0, 5, 3), Rect::new(2, 1, 0, 0)];
+        assert_eq!(
+            render_ascii(&cells, area),
+            "+---+\n|0* |\n+---+\nzero-size: 1\n"
+        );
+    }
+
+    #[test]
+    fn wide_areas_are_scaled_down() {
+        let area = Rect::new(0, 0, 200, 40);
+        let diagram = render_ascii(&auto_grid(area, 4, 0), area);
+        let lines: Vec<&str> = diagram.lines().collect();
+
+        assert_eq!(lines.len(), 14);
+        assert!(lines.iter().all(|line| line.len() <= 80));
+        assert_eq!(lines[0].matches('+').count(), 4);
+        assert!(lines[1].starts_with("|0") && lines[1].contains("|1"));
+    }
+
+    #[test]
+    fn debug_render_two_by_two() {
+        let area = Rect::new(5, 5, 12, 7);
+        let diagram = debug_render(area, &auto_grid(area, 4, 1));
+        assert_eq!(
+            diagram,
+            "\
+0----+ 1---+
+|    | |   |
++----+ +---+
+
+2----+ 3---+
+|    | |   |
++----+ +---+
+"
+        );
+    }
+
+    #[test]
+    fn debug_render_is_not_scaled() {
+        let area = Rect::new(0, 0, 200, 4);
+        let diagram = debug_render(area, &auto_grid(area, 2, 0));
+        let first = diagram.lines().next().unwrap();
+
+        assert_eq!(first.len(), 200);
+        assert!(first.starts_with("0---") && first[100..].starts_with("1---"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-widget", ratatui_widgets))]
+    fn debug_widget_draws_centered_indices() {
+        use ratatui::Terminal;
+        use ratatui::backend::TestBackend;
+
+        let mut terminal = Terminal::new(TestBackend::new(15, 7)).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(GridDebugWidget::new(3, 1), frame.area()))
+            .unwrap();
+
+        terminal.backend().assert_buffer_lines([
+            "┌─────┐ ┌─────┐",
+            "│  0  │ │  1  │",
+            "└─────┘ └─────┘",
+            "               ",
+            "┌─────┐        ",
+            "│  2  │        ",
+            "└─────┘        ",
+        ]);
+    }
+}
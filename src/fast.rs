@@ -0,0 +1,171 @@
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+/// Arranges `n` items in an automatic grid layout.
+///
+/// This was the integer arithmetic counterpart of the `Layout`-based
+/// [`auto_grid`](crate::auto_grid). Now that [`auto_grid`](crate::auto_grid) uses the same
+/// arithmetic, this is an alias of it.
+#[deprecated(note = "use `auto_grid`, which computes the same cells")]
+pub fn auto_grid_fast(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    crate::auto_grid(area, n, spacing)
+}
+
+/// Appends the cells of [`auto_grid`](crate::auto_grid) to `out`, using a `C` to hold the columns
+/// while the rows are filled.
+#[cfg(feature = "smallvec")]
+pub(crate) fn extend_fast<C>(out: &mut impl Extend<Rect>, area: Rect, n: usize, spacing: u16)
+where
+    C: Default + Extend<(u16, u16)> + AsRef<[(u16, u16)]>,
+{
+    let empty = crate::empty_cell(area);
+    if area.is_empty() {
+        out.extend(core::iter::repeat_n(empty, n));
+        return;
+    }
+
+    let (rows, cols) = crate::dimensions(n);
+    let mut columns = C::default();
+    columns.extend(split_axis(area.x, area.width, cols, spacing));
+
+    let mut remaining = n;
+    for (y, height) in split_axis(area.y, area.height, rows, spacing) {
+        let count = remaining.min(usize::from(cols));
+        out.extend(columns.as_ref()[..count].iter().map(|&(x, width)| {
+            if width == 0 || height == 0 {
+                empty
+            } else {
+                crate::new_rect(x, y, width, height)
+            }
+        }));
+        remaining -= count;
+    }
+    out.extend(core::iter::repeat_n(empty, remaining));
+}
+
+/// Splits `length` cells starting at `start` into `tracks` tracks separated by `spacing`, yielding
+/// the `(position, size)` of each track.
+///
+/// Once the spacing is removed, every track gets an even share of the length and the cells left
+/// over go one each to the first tracks. When the spacing doesn't leave any room, tracks get a size
+/// of 0 and their position is clamped to the end of the axis.
+pub(crate) fn split_axis(
+    start: u16,
+    length: u16,
+    tracks: u16,
+    spacing: u16,
+) -> impl Iterator<Item = (u16, u16)> {
+    let (start, length, spacing) = (u64::from(start), u64::from(length), u64::from(spacing));
+    let end = start + length;
+
+    let divisor = u64::from(tracks.max(1));
+    let available = length.saturating_sub(spacing * (divisor - 1));
+    let (size, extra) = (available / divisor, available % divisor);
+    let mut position = start;
+
+    (0..u64::from(tracks)).map(move |i| {
+        let size = size + u64::from(i < extra);
+        let track = (position.min(end) as u16, size as u16);
+        position += size + spacing;
+        track
+    })
+}
+
+/// Returns the `(position, size)` of the track at `index` of [`split_axis`], without computing the
+/// tracks before it.
+pub(crate) fn axis_track(
+    start: u16,
+    length: u16,
+    tracks: u16,
+    spacing: u16,
+    index: u16,
+) -> (u16, u16) {
+    let (start, length, spacing) = (u64::from(start), u64::from(length), u64::from(spacing));
+    let index = u64::from(index);
+
+    let divisor = u64::from(tracks.max(1));
+    let available = length.saturating_sub(spacing * (divisor - 1));
+    let (size, extra) = (available / divisor, available % divisor);
+
+    let position = start + index * (size + spacing) + index.min(extra);
+    let size = size + u64::from(index < extra);
+    (position.min(start + length) as u16, size as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[cfg(feature = "smallvec")]
+    fn extended(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+        let mut out = Vec::new();
+        extend_fast::<Vec<_>>(&mut out, area, n, spacing);
+        out
+    }
+
+    #[test]
+    fn exact_count_returned() {
+        for n in 0..=40 {
+            let result = auto_grid(Rect::new(0, 0, 100, 100), n, 1);
+            assert_eq!(result.len(), n);
+        }
+    }
+
+    #[test]
+    fn all_cells_within_bounds() {
+        for spacing in [0, 1, 3, 60] {
+            for n in 1..=40 {
+                let area = Rect::new(10, 10, 200, 150);
+                for rect in auto_grid(area, n, spacing) {
+                    assert!(rect.x >= area.x && rect.y >= area.y);
+                    assert!(rect.right() <= area.right());
+                    assert!(rect.bottom() <= area.bottom());
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "smallvec")]
+    fn matches_auto_grid() {
+        for (width, height) in [(100, 100), (99, 37), (13, 200), (7, 7), (0, 5)] {
+            let area = Rect::new(3, 4, width, height);
+            for spacing in [0, 1, 2, 30] {
+                for n in 1..=40 {
+                    assert_eq!(
+                        extended(area, n, spacing),
+                        auto_grid(area, n, spacing),
+                        "area {area:?}, n {n}, spacing {spacing}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn axis_track_matches_split_axis() {
+        for (start, length) in [(0, 100), (7, 99), (3, 13), (0, 0), (u16::MAX - 10, 10)] {
+            for tracks in 1..=12 {
+                for spacing in [0, 1, 2, 30] {
+                    for (index, track) in split_axis(start, length, tracks, spacing).enumerate() {
+                        let index = index as u16;
+                        assert_eq!(axis_track(start, length, tracks, spacing, index), track);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spacing_is_removed_before_splitting() {
+        let area = Rect::new(0, 0, 101, 50);
+        let result = auto_grid(area, 4, 2);
+
+        assert_eq!(result[0], Rect::new(0, 0, 50, 24));
+        assert_eq!(result[1], Rect::new(52, 0, 49, 24));
+        assert_eq!(result[2], Rect::new(0, 26, 50, 24));
+        assert_eq!(result[3], Rect::new(52, 26, 49, 24));
+    }
+}
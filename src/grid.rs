@@ -0,0 +1,418 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Index;
+
+use ratatui::layout::{Position, Rect};
+
+use crate::{DimensionHint, auto_grid, grid_shape, hit_test};
+
+/// The cell of a [`Grid`] containing a position, returned by [`Grid::hit_test_detailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellHit {
+    /// The index of the cell, in row-major order.
+    pub index: usize,
+    /// The row of the cell.
+    pub row: u16,
+    /// The column of the cell.
+    pub col: u16,
+    /// The horizontal offset of the position from the left edge of the cell.
+    pub local_x: u16,
+    /// The vertical offset of the position from the top edge of the cell.
+    pub local_y: u16,
+}
+
+/// A computed grid whose cells can be subdivided into nested grids.
+///
+/// Cells can be accessed by `(row, col)`, either with [`Grid::get`] or by indexing the grid.
+/// Nested grids are recorded on their parent so [`Grid::hit_test`] can resolve a position through
+/// every level of nesting.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::Grid;
+///
+/// let mut grid = Grid::new(Rect::new(0, 0, 100, 100), 4, 0);
+/// grid.nest(3, 4, 0);
+///
+/// // Bottom right cell of the bottom right cell.
+/// assert_eq!(grid.hit_test(99, 99), Some(vec![3, 3]));
+/// assert_eq!(grid[(1, 1)], Rect::new(50, 50, 50, 50));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    cells: Vec<Rect>,
+    rows: u16,
+    cols: u16,
+    children: Vec<Option<Grid>>,
+}
+
+impl Grid {
+    /// Creates a grid of `n` cells laid out with [`auto_grid`].
+    pub fn new(area: Rect, n: usize, spacing: u16) -> Self {
+        let (rows, cols) = grid_shape(n, DimensionHint::ColumnsFirst);
+        let cells = auto_grid(area, n, spacing);
+        let children = vec![None; cells.len()];
+        Self {
+            cells,
+            rows,
+            cols,
+            children,
+        }
+    }
+
+    /// Returns the cells of this grid, in row-major order.
+    pub fn cells(&self) -> &[Rect] {
+        &self.cells
+    }
+
+    /// Returns the number of rows of this grid.
+    pub fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    /// Returns the number of columns of this grid.
+    pub fn cols(&self) -> u16 {
+        self.cols
+    }
+
+    /// Returns the cell at `(row, col)`, or `None` if it's outside the grid or one of the empty
+    /// trailing slots of a partial last row.
+    pub fn get(&self, row: u16, col: u16) -> Option<Rect> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let index = usize::from(row) * usize::from(self.cols) + usize::from(col);
+        self.cells.get(index).copied()
+    }
+
+    /// Returns the grid nested in the cell at `index`, if any.
+    pub fn child(&self, index: usize) -> Option<&Grid> {
+        self.children.get(index)?.as_ref()
+    }
+
+    /// Subdivides the cell at `index` into a nested grid of `n` cells and returns it.
+    ///
+    /// Any grid previously nested in that cell is replaced. The returned grid can itself be nested
+    /// further.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn nest(&mut self, index: usize, n: usize, spacing: u16) -> &mut Grid {
+        let child = Grid::new(self.cells[index], n, spacing);
+        self.children[index].insert(child)
+    }
+
+    /// Returns the path of cell indices containing the position `(x, y)`.
+    ///
+    /// The first element is the index of the cell in this grid, followed by the index in each
+    /// nested grid. Returns `None` if the position doesn't fall in any cell. A position in the
+    /// spacing of a nested grid resolves to the parent cell only.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<Vec<usize>> {
+        let index = hit_test(&self.cells, x, y)?;
+
+        let mut path = vec![index];
+        if let Some(child) = &self.children[index]
+            && let Some(rest) = child.hit_test(x, y)
+        {
+            path.extend(rest);
+        }
+        Some(path)
+    }
+
+    /// Returns the cell of this grid containing the position `(x, y)`, with the position relative
+    /// to the top-left corner of the cell.
+    ///
+    /// The row and column are found by a binary search over the tracks, without scanning the
+    /// cells. Returns `None` if the position doesn't fall in any cell, for example when it lands in
+    /// the spacing between cells. Nested grids are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::Grid;
+    ///
+    /// let grid = Grid::new(Rect::new(0, 0, 100, 100), 4, 2);
+    /// let hit = grid.hit_test_detailed(99, 60).unwrap();
+    /// assert_eq!((hit.index, hit.row, hit.col), (3, 1, 1));
+    /// assert_eq!((hit.local_x, hit.local_y), (48, 9));
+    /// assert_eq!(grid.hit_test_detailed(50, 20), None);
+    /// ```
+    pub fn hit_test_detailed(&self, x: u16, y: u16) -> Option<CellHit> {
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        // The cells without room are moved to the origin, after the last track that has some.
+        let cols = usize::from(self.cols);
+        let col = partition_point(cols.min(self.cells.len()), |col| {
+            let cell = self.cells[col];
+            !cell.is_empty() && cell.right() <= x
+        });
+        let row = partition_point(self.cells.len().div_ceil(cols), |row| {
+            let cell = self.cells[row * cols];
+            !cell.is_empty() && cell.bottom() <= y
+        });
+
+        let (row, col) = (u16::try_from(row).ok()?, u16::try_from(col).ok()?);
+        let cell = self.get(row, col)?;
+        cell.contains(Position::new(x, y)).then(|| CellHit {
+            index: usize::from(row) * cols + usize::from(col),
+            row,
+            col,
+            local_x: x - cell.x,
+            local_y: y - cell.y,
+        })
+    }
+
+    /// Returns the index at which an item dropped at the position `(x, y)` should be inserted
+    /// among the cells of this grid, in `0..=n` like [`Vec::insert`].
+    ///
+    /// The row is the one spanning `y`, the first row for a position above the grid, and the
+    /// position is past the last item below the grid. Within a row, a position over the left half
+    /// of a cell, including its middle column when the width is odd, inserts before that cell, and
+    /// a position over its right half inserts after it. In the spacing between cells, the position
+    /// inserts before the next cell, so after the last cell of a row when past it, as over the
+    /// empty slots of a partial last row. A position in the spacing between two rows inserts at
+    /// the end of the row above. Cells without room are ignored, and nested grids too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::Grid;
+    ///
+    /// // 2 rows of 3 cells 10 wide, the last row with 2 cells.
+    /// let grid = Grid::new(Rect::new(0, 0, 32, 11), 5, 1);
+    /// assert_eq!(grid.drop_index(4, 2), 0);
+    /// assert_eq!(grid.drop_index(5, 2), 1);
+    /// assert_eq!(grid.drop_index(15, 2), 1);
+    /// // In the spacing between the rows, and over an empty slot.
+    /// assert_eq!(grid.drop_index(3, 5), 3);
+    /// assert_eq!(grid.drop_index(25, 8), 5);
+    /// ```
+    pub fn drop_index(&self, x: u16, y: u16) -> usize {
+        let (n, cols) = (self.cells.len(), usize::from(self.cols));
+        for start in (0..n).step_by(cols.max(1)) {
+            let first = self.cells[start];
+            if first.is_empty() {
+                break;
+            }
+            if start > 0 && y < first.y {
+                return start;
+            }
+            if y < first.bottom() {
+                let row = &self.cells[start..(start + cols).min(n)];
+                let before = row
+                    .iter()
+                    .filter(|cell| !cell.is_empty() && x >= cell.x + cell.width.div_ceil(2))
+                    .count();
+                return start + before;
+            }
+        }
+        n
+    }
+}
+
+impl Index<(u16, u16)> for Grid {
+    type Output = Rect;
+
+    /// Returns the cell at `(row, col)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no cell at `(row, col)`, see [`Grid::get`].
+    fn index(&self, (row, col): (u16, u16)) -> &Rect {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({row}, {col}) is outside of a {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        &self.cells[usize::from(row) * usize::from(self.cols) + usize::from(col)]
+    }
+}
+
+/// Returns the first index in `0..len` for which `pred` is false, `pred` being true then false.
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_by_row_and_column() {
+        let area = Rect::new(0, 0, 90, 60);
+        let grid = Grid::new(area, 5, 0);
+        let cells = auto_grid(area, 5, 0);
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(0, 0)], cells[0]);
+        assert_eq!(grid[(0, 2)], cells[2]);
+        assert_eq!(grid[(1, 1)], cells[4]);
+        assert_eq!(grid.get(1, 0), Some(cells[3]));
+    }
+
+    #[test]
+    fn get_empty_slot() {
+        let grid = Grid::new(Rect::new(0, 0, 90, 60), 5, 0);
+
+        assert_eq!(grid.get(1, 2), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get(2, 0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_empty_slot() {
+        let grid = Grid::new(Rect::new(0, 0, 90, 60), 5, 0);
+        let _ = grid[(1, 2)];
+    }
+
+    #[test]
+    fn nested_hit_test() {
+        let mut grid = Grid::new(Rect::new(0, 0, 100, 100), 4, 0);
+        grid.nest(2, 4, 0);
+
+        assert_eq!(grid.hit_test(10, 10), Some(vec![0]));
+        // Top right sub-cell of the bottom left cell.
+        assert_eq!(grid.hit_test(30, 60), Some(vec![2, 1]));
+        assert_eq!(grid.hit_test(100, 100), None);
+    }
+
+    #[test]
+    fn arbitrary_depth() {
+        let mut grid = Grid::new(Rect::new(0, 0, 100, 100), 4, 0);
+        grid.nest(0, 4, 0).nest(3, 4, 0);
+
+        assert_eq!(grid.hit_test(49, 49), Some(vec![0, 3, 3]));
+        assert_eq!(grid.hit_test(0, 0), Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn child_cells_stay_within_parent() {
+        let mut grid = Grid::new(Rect::new(3, 7, 91, 53), 5, 1);
+        for index in 0..grid.cells().len() {
+            grid.nest(index, 7, 1);
+        }
+
+        for (index, parent) in grid.cells().iter().enumerate() {
+            let child = grid.child(index).unwrap();
+            for cell in child.cells() {
+                assert_eq!(parent.intersection(*cell), *cell);
+            }
+        }
+    }
+
+    #[test]
+    fn spacing_resolves_to_parent() {
+        let mut grid = Grid::new(Rect::new(0, 0, 100, 100), 1, 0);
+        grid.nest(0, 4, 2);
+
+        let gap_x = grid.child(0).unwrap().cells()[0].right();
+        assert_eq!(grid.hit_test(gap_x, 0), Some(vec![0]));
+    }
+
+    #[test]
+    fn detailed_hit_at_cell_corners() {
+        let grid = Grid::new(Rect::new(3, 2, 62, 31), 7, 1);
+        for (index, cell) in grid.cells().iter().enumerate() {
+            let (row, col) = ((index / 3) as u16, (index % 3) as u16);
+            for (local_x, local_y) in [
+                (0, 0),
+                (cell.width - 1, 0),
+                (0, cell.height - 1),
+                (cell.width - 1, cell.height - 1),
+            ] {
+                let hit = grid.hit_test_detailed(cell.x + local_x, cell.y + local_y);
+                let expected = CellHit {
+                    index,
+                    row,
+                    col,
+                    local_x,
+                    local_y,
+                };
+                assert_eq!(hit, Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn detailed_hit_misses() {
+        let grid = Grid::new(Rect::new(3, 2, 62, 31), 7, 1);
+        let [first, second] = [grid.cells()[0], grid.cells()[1]];
+        // The spacing between the first two columns and rows.
+        assert_eq!(grid.hit_test_detailed(first.right(), first.y), None);
+        assert_eq!(grid.hit_test_detailed(first.x, first.bottom()), None);
+        // The empty slots of the last row, and outside of the grid.
+        assert_eq!(grid.hit_test_detailed(second.x, 32), None);
+        assert_eq!(grid.hit_test_detailed(0, 0), None);
+        assert_eq!(grid.hit_test_detailed(65, 2), None);
+
+        // Only the first 2 of the 4 cells get room.
+        let grid = Grid::new(Rect::new(0, 0, 2, 1), 4, 0);
+        assert_eq!(grid.hit_test_detailed(1, 0).map(|hit| hit.index), Some(1));
+        assert_eq!(grid.hit_test_detailed(2, 0), None);
+        assert_eq!(
+            Grid::new(Rect::new(0, 0, 9, 9), 0, 0).hit_test_detailed(0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn drop_index_by_cell_half() {
+        let grid = Grid::new(Rect::new(2, 3, 62, 31), 7, 1);
+        for (index, cell) in grid.cells().iter().enumerate() {
+            let middle = cell.x + cell.width.div_ceil(2);
+            for y in [cell.y, cell.bottom() - 1] {
+                for x in cell.x..middle {
+                    assert_eq!(grid.drop_index(x, y), index, "({x}, {y})");
+                }
+                for x in middle..cell.right() {
+                    assert_eq!(grid.drop_index(x, y), index + 1, "({x}, {y})");
+                }
+            }
+            // The spacing on the right of the cell.
+            assert_eq!(grid.drop_index(cell.right(), cell.y), index + 1);
+        }
+    }
+
+    #[test]
+    fn drop_index_around_the_rows() {
+        let area = Rect::new(2, 3, 62, 31);
+        let grid = Grid::new(area, 7, 1);
+        let [first, last] = [grid.cells()[0], grid.cells()[6]];
+
+        // Above, left of and below the grid.
+        assert_eq!(grid.drop_index(25, 0), 1);
+        assert_eq!(grid.drop_index(0, last.y), 6);
+        assert_eq!(grid.drop_index(0, 40), 7);
+        // The spacing between the first two rows.
+        assert_eq!(grid.drop_index(first.x, first.bottom()), 3);
+        // The empty slots of the last row.
+        for x in last.right()..area.right() + 5 {
+            assert_eq!(grid.drop_index(x, last.y), 7);
+        }
+
+        assert_eq!(Grid::new(area, 0, 1).drop_index(10, 10), 0);
+        assert_eq!(Grid::new(Rect::new(0, 0, 2, 1), 4, 0).drop_index(5, 0), 2);
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::vec::Vec;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use ratatui::layout::Rect;
+
+use crate::auto_grid;
+
+/// The cells of an automatic grid layout, keyed by the items they were assigned to.
+///
+/// The cells keep the layout order: [`KeyedGrid::iter`] visits them in row-major order, while
+/// [`KeyedGrid::get`] looks a cell up by its key.
+#[derive(Debug, Clone)]
+pub struct KeyedGrid<K> {
+    cells: Vec<(K, Rect)>,
+    index: HashMap<K, usize>,
+}
+
+/// An error returned by [`auto_grid_map`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyedGridError {
+    /// A key was already given to an earlier item.
+    Duplicate {
+        /// The position of the second occurrence of the key.
+        index: usize,
+    },
+}
+
+impl fmt::Display for KeyedGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate { index } => write!(f, "key at index {index} is duplicated"),
+        }
+    }
+}
+
+impl core::error::Error for KeyedGridError {}
+
+/// Arranges one item per key with [`auto_grid`], assigning the cells to the keys in iteration
+/// order.
+///
+/// # Errors
+///
+/// Returns [`KeyedGridError::Duplicate`] if a key appears more than once.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_map;
+///
+/// let grid = auto_grid_map(Rect::new(0, 0, 20, 10), ["cpu", "mem", "net"], 0).unwrap();
+/// assert_eq!(grid.get(&"net"), Some(Rect::new(0, 5, 10, 5)));
+///
+/// let keys: Vec<_> = grid.iter().map(|(key, _)| *key).collect();
+/// assert_eq!(keys, ["cpu", "mem", "net"]);
+/// ```
+pub fn auto_grid_map<K: Eq + Hash + Clone>(
+    area: Rect,
+    keys: impl IntoIterator<Item = K>,
+    spacing: u16,
+) -> Result<KeyedGrid<K>, KeyedGridError> {
+    let keys: Vec<K> = keys.into_iter().collect();
+    let mut index = HashMap::with_capacity(keys.len());
+    for (i, key) in keys.iter().enumerate() {
+        if index.insert(key.clone(), i).is_some() {
+            return Err(KeyedGridError::Duplicate { index: i });
+        }
+    }
+
+    let cells = auto_grid(area, keys.len(), spacing);
+    Ok(KeyedGrid {
+        cells: keys.into_iter().zip(cells).collect(),
+        index,
+    })
+}
+
+impl<K: PartialEq> PartialEq for KeyedGrid<K> {
+    fn eq(&self, other: &Self) -> bool {
+        // The index is derived from the cells, so comparing the cells is enough.
+        self.cells == other.cells
+    }
+}
+
+impl<K: Eq> Eq for KeyedGrid<K> {}
+
+impl<K: Eq + Hash> KeyedGrid<K> {
+    /// Returns the cell of `key`, if it was given to an item.
+    pub fn get(&self, key: &K) -> Option<Rect> {
+        self.index.get(key).map(|&i| self.cells[i].1)
+    }
+
+    /// Returns the number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether there are no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the keys and their cells in layout order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Rect)> {
+        self.cells.iter().map(|(key, cell)| (key, *cell))
+    }
+
+    /// Returns the keys and their cells in layout order, as a slice.
+    pub fn as_slice(&self) -> &[(K, Rect)] {
+        &self.cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_follow_the_layout_order() {
+        let area = Rect::new(2, 1, 60, 30);
+        let grid = auto_grid_map(area, (10..17).rev(), 1).unwrap();
+        let cells = auto_grid(area, 7, 1);
+
+        assert_eq!(grid.len(), 7);
+        for ((i, (key, cell)), expected) in grid.iter().enumerate().zip(&cells) {
+            assert_eq!(*key, 16 - i);
+            assert_eq!(cell, *expected);
+            assert_eq!(grid.get(key), Some(cell));
+        }
+        assert_eq!(grid.get(&3), None);
+    }
+
+    #[test]
+    fn duplicate_keys_are_rejected() {
+        let area = Rect::new(0, 0, 10, 10);
+        assert_eq!(
+            auto_grid_map(area, ["a", "b", "a"], 0),
+            Err(KeyedGridError::Duplicate { index: 2 })
+        );
+        assert!(auto_grid_map(area, Vec::<u8>::new(), 0).unwrap().is_empty());
+    }
+}
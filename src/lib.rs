@@ -1,4 +1,104 @@
-use ratatui::layout::{Constraint, Layout, Rect};
+#![no_std]
+
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+
+use crate::fast::split_axis;
+
+mod animate;
+#[cfg(feature = "std")]
+mod areas;
+mod breakpoints;
+mod builder;
+#[cfg(feature = "std")]
+mod cache;
+mod cursor;
+mod debug;
+mod fast;
+mod grid;
+#[cfg(feature = "std")]
+mod keyed;
+mod masonry;
+mod order;
+mod pack;
+mod render;
+mod scroll;
+#[cfg(feature = "smallvec")]
+mod small;
+mod spans;
+mod state;
+mod template;
+mod tiling;
+
+pub use animate::{GridTransition, lerp_grids, lerp_layout};
+#[cfg(feature = "std")]
+pub use areas::{GridTemplate, GridTemplateError};
+pub use breakpoints::{Breakpoints, BreakpointsError};
+pub use builder::{AutoGrid, Balance, Gravity, VGravity};
+#[cfg(feature = "std")]
+pub use cache::{CacheStats, GridCache};
+pub use cursor::GridCursor;
+#[cfg(all(feature = "debug-widget", ratatui_widgets))]
+pub use debug::GridDebugWidget;
+pub use debug::{debug_render, render_ascii};
+#[allow(deprecated)]
+pub use fast::auto_grid_fast;
+pub use grid::{CellHit, Grid};
+#[cfg(feature = "std")]
+pub use keyed::{KeyedGrid, KeyedGridError, auto_grid_map};
+pub use masonry::masonry;
+pub use order::{
+    auto_grid_balanced_columns, auto_grid_by_key, auto_grid_diagonal, auto_grid_spiral,
+};
+pub use pack::pack;
+#[cfg(all(feature = "widget-ref", ratatui_widgets))]
+pub use render::render_widgets_ref;
+pub use render::{
+    auto_grid_zip, auto_grid_zip_mut, for_each_cell, render_widgets, try_for_each_cell,
+};
+pub use scroll::{
+    ScrollableGrid, VirtualGrid, VirtualLayout, auto_grid_clipped, auto_grid_fixed_density,
+    auto_grid_scroll,
+};
+#[cfg(feature = "smallvec")]
+pub use small::{INLINE_CELLS, auto_grid_small};
+pub use spans::{CellSpan, auto_grid_spans};
+pub use state::{GridState, auto_grid_resizable};
+pub use template::{
+    ParseTemplateError, ParseTemplateErrorKind, Track, TrackTemplate, auto_grid_template,
+};
+pub use tiling::{bsp_grid, golden_grid, master_stack, spiral_grid};
+
+/// The version of ratatui this crate is built against, 0.28 with the `ratatui-028` feature.
+///
+/// The layouts take and return its [`Rect`], so applications that depend on a different version of
+/// ratatui can use this re-export to build the rects they pass in.
+#[cfg(all(feature = "ratatui-028", not(feature = "ratatui-latest")))]
+pub extern crate ratatui_028 as ratatui;
+
+/// The version of ratatui this crate is built against, the latest with the `ratatui-latest`
+/// feature, which takes precedence over `ratatui-028` when both are enabled.
+///
+/// The layouts take and return its [`Rect`], so applications that depend on a different version of
+/// ratatui can use this re-export to build the rects they pass in.
+#[cfg(feature = "ratatui-latest")]
+pub use ratatui;
+
+/// The `no_std` core of ratatui this crate is built against when neither the `ratatui-028` nor the
+/// `ratatui-latest` feature is enabled.
+///
+/// The layouts take and return its [`Rect`], which is also the one of ratatui 0.30 and later.
+#[cfg(not(any(feature = "ratatui-028", feature = "ratatui-latest")))]
+pub extern crate ratatui_core as ratatui;
+
+/// Largest number of items that get a slot in a grid, `u16::MAX` rows of `u16::MAX` columns.
+pub const MAX_CELLS: usize = u16::MAX as usize * u16::MAX as usize;
 
 /// Arranges `n` items in an automatic grid layout within the given area.
 ///
@@ -14,11 +114,29 @@ use ratatui::layout::{Constraint, Layout, Rect};
 ///
 /// # Returns
 ///
-/// A vector of `n` Rects, arranged in row-major order (left-to-right, top-to-bottom)
+/// A vector of exactly `n` Rects, arranged in row-major order (left-to-right, top-to-bottom).
+///
+/// When the area is too small to give every item a cell of at least 1×1, the items that don't get
+/// room come back as zero-size rects at the origin of `area`. This includes every item when `area`
+/// has a width or height of 0, or when the spacing takes up all of it. Non-empty cells never
+/// overlap and every cell lies within `area`.
+///
+/// Spacing larger than `area` allows is handled on a best-effort basis: it is never shrunk, and
+/// the length left once the gaps are removed is split over the tracks as usual. Tracks that end up
+/// with no room come back as zero-size cells, so no cell wraps around or extends past `area`
+/// whatever the spacing.
+///
+/// Within a row, cell widths differ by at most 1, and so do row heights. Once the spacing is
+/// removed, the length left over by an even split goes one cell at a time to the leftmost columns
+/// and the topmost rows.
+///
+/// The grid has at most `u16::MAX` rows and columns, so at most [`MAX_CELLS`] items get a slot in
+/// it. Any item past that comes back as a zero-size rect at the origin of `area`.
 ///
 /// # Example
 ///
 /// ```
+/// # use ratatui_auto_grid::ratatui;
 /// use ratatui::layout::Rect;
 /// use ratatui_auto_grid::auto_grid;
 ///
@@ -27,171 +145,2912 @@ use ratatui::layout::{Constraint, Layout, Rect};
 /// assert_eq!(cells.len(), 9);
 /// ```
 pub fn auto_grid(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let out = grid_cells(area, n, dimensions(n), spacing, spacing);
+
+    debug_assert!(
+        out.iter().all(|&cell| contains(area, cell)),
+        "auto_grid produced cells outside of {area:?}: {out:?}"
+    );
+    out
+}
+
+/// Alias of [`auto_grid`] for callers that rely on its integer rounding.
+///
+/// [`auto_grid`] doesn't go through ratatui's `Layout` solver: once the spacing is removed, each
+/// track gets the length divided by the number of tracks, rounded down, and the remainder goes one
+/// cell at a time to the leftmost columns and topmost rows. Calling this function instead records
+/// that the caller depends on that rounding, which is guaranteed not to change with the version of
+/// ratatui.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_exact;
+///
+/// // 3 columns sharing 100 - 2 = 98 cells: 33, 33 and 32.
+/// let cells = auto_grid_exact(Rect::new(0, 0, 100, 30), 9, 1);
+/// assert_eq!(cells[0], Rect::new(0, 0, 33, 10));
+/// assert_eq!(cells[1], Rect::new(34, 0, 33, 10));
+/// assert_eq!(cells[2], Rect::new(68, 0, 32, 10));
+/// ```
+pub fn auto_grid_exact(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    auto_grid(area, n, spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout with fractional cell positions and sizes.
+///
+/// The grid has the shape of [`auto_grid`], but the length left once the spacing is removed is
+/// divided between the tracks in floating point, without any rounding: every column is
+/// `(area.width - spacing * (cols - 1)) / cols` wide, and likewise for the rows. This is meant
+/// for renderers with sub-cell precision, like image backends. Like in [`auto_grid`], items that
+/// don't get room, because the spacing takes up all the length or because they don't have a slot
+/// in the grid, come back at the origin of `area` with a size of 0.
+///
+/// # Returns
+///
+/// The `(x, y, width, height)` of the `n` cells, in row-major order.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_f32;
+///
+/// let cells = auto_grid_f32(Rect::new(0, 0, 100, 10), 3, 2);
+/// assert_eq!(cells[0], (0.0, 0.0, 49.0, 4.0));
+/// assert_eq!(cells[2], (0.0, 6.0, 49.0, 4.0));
+///
+/// let cells = auto_grid_f32(Rect::new(0, 0, 10, 10), 3, 0);
+/// assert_eq!(cells[1].0, 5.0);
+/// ```
+pub fn auto_grid_f32(area: Rect, n: usize, spacing: u16) -> Vec<(f32, f32, f32, f32)> {
+    let (rows, cols) = dimensions(n);
+    let spacing = f32::from(spacing);
+    let track = |length: u16, tracks: u16| {
+        let gaps = spacing * f32::from(tracks.saturating_sub(1));
+        ((f32::from(length) - gaps) / f32::from(tracks.max(1))).max(0.0)
+    };
+    let (width, height) = (track(area.width, cols), track(area.height, rows));
+    let (x, y) = (f32::from(area.x), f32::from(area.y));
+
+    let slots = usize::from(rows) * usize::from(cols);
+    (0..n)
+        .map(|index| {
+            if index >= slots || width == 0.0 || height == 0.0 {
+                return (x, y, 0.0, 0.0);
+            }
+            let (row, col) = (index / usize::from(cols), index % usize::from(cols));
+            (
+                x + col as f32 * (width + spacing),
+                y + row as f32 * (height + spacing),
+                width,
+                height,
+            )
+        })
+        .collect()
+}
+
+/// Arranges `n` items in a grid with as many rows as columns.
+///
+/// Both dimensions are √n rounded up, so the grid is square even when [`auto_grid`] would drop
+/// its last row: for `n = 5`, this gives 3 rows of 3 columns instead of 2 rows of 3 columns. The
+/// slots past the last item are left empty, with the rows sized as if they were filled.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_square_dims;
+///
+/// let cells = auto_grid_square_dims(Rect::new(0, 0, 90, 90), 5, 0);
+/// assert_eq!(cells.len(), 5);
+/// assert_eq!(cells[4], Rect::new(30, 30, 30, 30));
+/// ```
+pub fn auto_grid_square_dims(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let side = ceil_sqrt(n.min(MAX_CELLS)) as u16;
+    grid_cells(area, n, (side, side), spacing, spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout, expressing the spacing as a percentage of the
+/// area.
+///
+/// `h_pct` is the gap between columns as a percentage of `area.width` and `v_pct` the gap between
+/// rows as a percentage of `area.height`. Percentages above 100 are treated as 100. The resulting
+/// spacing is clamped so every cell keeps at least one column and one row.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_spacing_pct;
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// // Gaps of 5 columns between cells and 2 rows between rows.
+/// let cells = auto_grid_spacing_pct(area, 4, 5, 2);
+/// assert_eq!(cells[1].x - (cells[0].x + cells[0].width), 5);
+/// ```
+pub fn auto_grid_spacing_pct(area: Rect, n: usize, h_pct: u8, v_pct: u8) -> Vec<Rect> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let (rows, cols) = dimensions(n);
+    let h_spacing = pct_spacing(area.width, cols, h_pct);
+    let v_spacing = pct_spacing(area.height, rows, v_pct);
+    grid_cells(area, n, (rows, cols), h_spacing, v_spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout, choosing the spacing so that cells of
+/// `cell_w`×`cell_h` fill `area`.
+///
+/// The spacing between columns is the width left over by the cells divided by the number of gaps,
+/// rounded down, and likewise between rows. What the rounding leaves goes one column or row at a
+/// time to the leftmost columns and topmost rows, like in [`auto_grid`], so the cells and the
+/// spacing span `area` exactly and cells may be one larger than requested. A single column or row
+/// has no gap and takes the whole width or height.
+///
+/// # Returns
+///
+/// The `n` cells with the horizontal and vertical spacing, or no cells and a spacing of 0 when the
+/// cells don't fit in `area` even without spacing.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_fit_spacing;
+///
+/// let (cells, h_spacing, v_spacing) = auto_grid_fit_spacing(Rect::new(0, 0, 50, 20), 6, 10, 8);
+/// assert_eq!((h_spacing, v_spacing), (10, 4));
+/// assert_eq!(cells[2], Rect::new(40, 0, 10, 8));
+/// assert_eq!(cells[5].bottom(), 20);
+/// ```
+pub fn auto_grid_fit_spacing(
+    area: Rect,
+    n: usize,
+    cell_w: u16,
+    cell_h: u16,
+) -> (Vec<Rect>, u16, u16) {
+    let (rows, cols) = dimensions(n);
+    match (
+        fit_spacing(area.width, cols, cell_w),
+        fit_spacing(area.height, rows, cell_h),
+    ) {
+        (Some(h_spacing), Some(v_spacing)) if n > 0 => (
+            grid_cells(area, n, (rows, cols), h_spacing, v_spacing),
+            h_spacing,
+            v_spacing,
+        ),
+        _ => (Vec::new(), 0, 0),
+    }
+}
+
+/// Arranges as many of `n` items as fit in an automatic grid layout with a minimum cell size.
+///
+/// Finds the largest number of items whose [`auto_grid`] cells are all at least `min_w` wide and
+/// `min_h` tall, and lays them out.
+///
+/// # Returns
+///
+/// The cells of the items that fit, and the number of items that were dropped. The two always add
+/// up to `n`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_clamped;
+///
+/// let area = Rect::new(0, 0, 20, 10);
+/// let (cells, dropped) = auto_grid_clamped(area, 9, 0, 10, 5);
+/// assert_eq!(cells.len(), 4);
+/// assert_eq!(dropped, 5);
+/// ```
+pub fn auto_grid_clamped(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    min_w: u16,
+    min_h: u16,
+) -> (Vec<Rect>, usize) {
+    let fits = |k: usize| {
+        let (rows, cols) = dimensions(k);
+        min_track_size(area.width, cols, spacing) >= min_w
+            && min_track_size(area.height, rows, spacing) >= min_h
+    };
+
+    // The grid dimensions only grow with the number of items, so the fitting counts are a prefix.
+    let (mut low, mut high) = (0, n);
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    (auto_grid(area, low, spacing), n - low)
+}
+
+/// Arranges `n` items in an automatic grid layout where cells have a minimum size and grow to fill
+/// the area.
+///
+/// Every column is a [`Constraint::Min`] of `min_w` and every row a [`Constraint::Min`] of `min_h`,
+/// so the tracks share any extra room in the area. When the minimums of the automatic grid don't
+/// all fit, the number of columns is reduced to what fits, then the number of rows. The items that
+/// no longer get a slot come back as zero-size rects at the origin of `area`, so the result still
+/// has exactly `n` cells. A single column or row shrinks below its minimum if the area is smaller.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_min_flex;
+///
+/// // Room for 2 columns and rows of at least 15×3, which grow to 20×5 each.
+/// let cells = auto_grid_min_flex(Rect::new(0, 0, 40, 10), 4, 0, 15, 3);
+/// assert_eq!(cells[0], Rect::new(0, 0, 20, 5));
+/// assert_eq!(cells[3], Rect::new(20, 5, 20, 5));
+/// ```
+pub fn auto_grid_min_flex(area: Rect, n: usize, spacing: u16, min_w: u16, min_h: u16) -> Vec<Rect> {
+    if n == 0 {
+        return Vec::new();
+    }
+    if area.is_empty() {
+        return vec![empty_cell(area); n];
+    }
+
+    let (_, cols) = dimensions(n);
+    let cols = cols.min(fitting_tracks(area.width, min_w, spacing));
+    let rows = n.div_ceil(usize::from(cols)).min(usize::from(u16::MAX)) as u16;
+    let rows = rows.min(fitting_tracks(area.height, min_h, spacing));
+
+    let mut out = split_constraints(
+        area,
+        &vec![Constraint::Min(min_h); usize::from(rows)],
+        &vec![Constraint::Min(min_w); usize::from(cols)],
+        spacing,
+        spacing,
+    );
+    out.resize(n, empty_cell(area));
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Arranges at most `max_visible` of `n` items in an automatic grid layout.
+///
+/// The visible cells are sized as if there were only `max_visible` items, so the overflow items can
+/// be rendered elsewhere.
+///
+/// # Returns
+///
+/// The cells of the visible items, and the number of overflow items.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_overflow;
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let (cells, overflow) = auto_grid_overflow(area, 10, 0, 4);
+/// assert_eq!(cells.len(), 4);
+/// assert_eq!(overflow, 6);
+/// ```
+pub fn auto_grid_overflow(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    max_visible: usize,
+) -> (Vec<Rect>, usize) {
+    let visible = n.min(max_visible);
+    (auto_grid(area, visible, spacing), n - visible)
+}
+
+/// Arranges `n` items in an automatic grid layout, hiding the items of a partial last row.
+///
+/// The cells are the slots of [`auto_grid`] for the items of the complete rows, so the hidden
+/// items leave their row empty rather than the other cells growing. When there are fewer items
+/// than columns, there is no complete row and every item is shown.
+///
+/// # Returns
+///
+/// The cells of the shown items, which are the first items in row-major order, and the number of
+/// hidden items. The two always add up to `n`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, auto_grid_full_rows};
+///
+/// let area = Rect::new(0, 0, 90, 90);
+/// let (cells, hidden) = auto_grid_full_rows(area, 8, 0);
+/// assert_eq!(cells, auto_grid(area, 8, 0)[..6]);
+/// assert_eq!(hidden, 2);
+/// ```
+pub fn auto_grid_full_rows(area: Rect, n: usize, spacing: u16) -> (Vec<Rect>, usize) {
+    let cols = usize::from(dimensions(n).1.max(1));
+    let shown = if n < cols { n } else { n - n % cols };
+
+    let mut cells = auto_grid(area, n, spacing);
+    cells.truncate(shown);
+    (cells, n - shown)
+}
+
+/// The overall shape of a grid, used by [`auto_grid_oriented`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The shape picked by [`auto_grid`].
+    #[default]
+    Auto,
+    /// At least as many columns as rows.
+    Landscape,
+    /// At least as many rows as columns.
+    Portrait,
+}
+
+/// Arranges `n` items in an automatic grid layout with the given orientation.
+///
+/// The square root approach of [`auto_grid`] already computes the columns first, so it never has
+/// more rows than columns: `Auto` and `Landscape` produce the same grid. `Portrait` swaps the rows
+/// and columns like [`DimensionHint::RowsFirst`].
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{Orientation, auto_grid_oriented};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// // 2 columns and 3 rows.
+/// let cells = auto_grid_oriented(area, 6, 0, Orientation::Portrait);
+/// assert_eq!(cells[2].x, cells[0].x);
+/// ```
+pub fn auto_grid_oriented(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    orientation: Orientation,
+) -> Vec<Rect> {
+    let hint = match orientation {
+        Orientation::Auto | Orientation::Landscape => DimensionHint::ColumnsFirst,
+        Orientation::Portrait => DimensionHint::RowsFirst,
+    };
+    auto_grid_with_hint(area, n, spacing, hint)
+}
+
+/// How the number of columns is rounded from √n, used by [`auto_grid_rounded`].
+///
+/// The rows are always derived from the columns as n/cols (rounded up), so that there is room
+/// for every item. For `n = 7` (√7 ≈ 2.65):
+///
+/// - `Ceil` gives 3 columns and 3 rows, with 2 empty slots.
+/// - `Floor` gives 2 columns and 4 rows, with 1 empty slot.
+/// - `Nearest` gives 3 columns and 3 rows, like `Ceil`. It differs from `Ceil` when the fractional
+///   part of √n is below one half, for example for `n = 5`, where it gives 2 columns and 3 rows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds up, like [`auto_grid`]. The grid is never taller than wide.
+    #[default]
+    Ceil,
+    /// Rounds down. The grid is never wider than tall.
+    Floor,
+    /// Rounds to the nearest integer, halves rounding up.
+    Nearest,
+}
+
+/// Arranges `n` items in an automatic grid layout, rounding the number of columns as given.
+///
+/// See [`Rounding`] for the resulting shapes. With [`Rounding::Ceil`], this is the same as
+/// [`auto_grid`].
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{Rounding, auto_grid_rounded};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// // 2 columns and 4 rows.
+/// let cells = auto_grid_rounded(area, 7, 0, Rounding::Floor);
+/// assert_eq!(cells[2], Rect::new(0, 25, 50, 25));
+/// assert_eq!(cells[6], Rect::new(0, 75, 50, 25));
+/// ```
+pub fn auto_grid_rounded(area: Rect, n: usize, spacing: u16, rounding: Rounding) -> Vec<Rect> {
+    grid_cells(area, n, rounded_dimensions(n, rounding), spacing, spacing)
+}
+
+/// Arranges `n` items in as many columns of at least `target_w` as the area can hold.
+///
+/// The number of columns is `(area.width + spacing) / (target_w + spacing)`, the most columns at
+/// least `target_w` wide that fit with `spacing` between them. There is at least 1 column, which
+/// is narrower than `target_w` when the area is, and a `target_w` of 0 is treated as 1. Rows are
+/// added as needed to fit all the items, filled in row-major order. Like [`auto_grid`], the grid
+/// has at most `u16::MAX` rows, and the items past them come back as zero-size rects at the origin
+/// of `area`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_target_width;
+///
+/// let area = Rect::new(0, 0, 120, 40);
+/// let cells = auto_grid_target_width(area, 6, 0, 30);
+/// // 4 columns, the last 2 items on a second row.
+/// assert_eq!(cells[3].x, 90);
+/// assert_eq!(cells[4].y, 20);
+/// ```
+pub fn auto_grid_target_width(area: Rect, n: usize, spacing: u16, target_w: u16) -> Vec<Rect> {
+    let cols = fitting_tracks(area.width, target_w.max(1), spacing);
+    let rows = n.div_ceil(usize::from(cols)).min(usize::from(u16::MAX)) as u16;
+    grid_cells(area, n, (rows, cols), spacing, spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout whose cells are at most `max_ratio` times wider
+/// than they are tall.
+///
+/// Starting from the grid of [`auto_grid`], rows are traded for columns until the width of the
+/// widest cells divided by the height of the shortest ones is at most `max_ratio`, the same
+/// measure as [`grid_fits`]. Cells that are too wide get narrower with more columns, and shorter
+/// with more rows, so the grid only ever loses rows. When no grid satisfies the ratio, even a
+/// single row, the one with the narrowest cells is used. Grids that would leave the cells with no
+/// width are never picked, so a `max_ratio` of 0 or below gives the narrowest cells that still have
+/// room.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_max_ratio;
+///
+/// // auto_grid gives 3 rows of 40×10, this gives 2 rows of 24×15.
+/// let cells = auto_grid_max_ratio(Rect::new(0, 0, 120, 30), 9, 0, 2.0);
+/// assert_eq!(cells[0], Rect::new(0, 0, 24, 15));
+/// assert_eq!(cells[8], Rect::new(72, 15, 24, 15));
+/// ```
+pub fn auto_grid_max_ratio(area: Rect, n: usize, spacing: u16, max_ratio: f32) -> Vec<Rect> {
+    let (rows, cols) = dimensions(n);
+    let mut best = (rows, cols);
+    let mut best_ratio = f32::INFINITY;
+    for rows in (1..=rows).rev() {
+        let Ok(cols) = u16::try_from(n.div_ceil(usize::from(rows))) else {
+            break;
+        };
+        let Some(ratio) = cell_ratio(area, (rows, cols), spacing) else {
+            break;
+        };
+        if ratio < best_ratio {
+            (best, best_ratio) = ((rows, cols), ratio);
+        }
+        if ratio <= max_ratio {
+            break;
+        }
+    }
+    grid_cells(area, n, best, spacing, spacing)
+}
+
+/// Returns whether the cells of [`auto_grid`] for `n` items in `area` would all be at least
+/// `min_w` wide and `min_h` tall, and at most `max_ratio` times wider than tall.
+///
+/// Only the grid dimensions are computed, without laying out the cells, so this is cheap enough
+/// to pick between a grid and another view before drawing. The ratio is the width of the widest
+/// cells divided by the height of the shortest ones, as in [`auto_grid_max_ratio`]. A grid where some item gets no room never fits, and a grid without
+/// items always does.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::grid_fits;
+///
+/// let area = Rect::new(0, 0, 120, 30);
+/// // 3 rows of 40×10 cells.
+/// assert!(grid_fits(area, 9, 0, 20, 5, 4.0));
+/// assert!(!grid_fits(area, 9, 0, 20, 5, 3.0));
+/// assert!(!grid_fits(area, 9, 0, 50, 5, 4.0));
+/// ```
+pub fn grid_fits(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    min_w: u16,
+    min_h: u16,
+    max_ratio: f32,
+) -> bool {
     if n == 0 {
+        return true;
+    }
+    if n > MAX_CELLS {
+        return false;
+    }
+
+    let (rows, cols) = dimensions(n);
+    min_track_size(area.width, cols, spacing) >= min_w.max(1)
+        && min_track_size(area.height, rows, spacing) >= min_h.max(1)
+        && cell_ratio(area, (rows, cols), spacing).is_some_and(|ratio| ratio <= max_ratio)
+}
+
+/// Arranges `n` items in an automatic grid layout with the same gap between cells and around them.
+///
+/// `area` is inset by `spacing` on all four sides before being split with `spacing` between cells,
+/// so every gap is identical. Returns an empty vector if the insets leave no room for cells.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_even_gutters;
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let cells = auto_grid_even_gutters(area, 4, 2);
+/// assert_eq!(cells[0].x, 2);
+/// assert_eq!(cells[3].right(), 98);
+/// ```
+pub fn auto_grid_even_gutters(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    if u32::from(spacing) * 2 >= u32::from(area.width.min(area.height)) {
+        return Vec::new();
+    }
+
+    let inner = area.inner(Margin::new(spacing, spacing));
+    auto_grid(inner, n, spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout within `area` inset by a margin on each side.
+///
+/// Each side of `area` is moved in by its own margin, for chrome on only some sides like a tab
+/// bar on top, and the rest is split like [`auto_grid`]. Returns an empty vector if the margins
+/// leave no room for cells.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_margins;
+///
+/// let cells = auto_grid_margins(Rect::new(0, 0, 40, 23), 4, 0, 3, 0, 0, 0);
+/// assert_eq!(cells[0], Rect::new(0, 3, 20, 10));
+/// assert_eq!(cells[3], Rect::new(20, 13, 20, 10));
+/// ```
+pub fn auto_grid_margins(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    top: u16,
+    right: u16,
+    bottom: u16,
+    left: u16,
+) -> Vec<Rect> {
+    let horizontal = u32::from(left) + u32::from(right);
+    let vertical = u32::from(top) + u32::from(bottom);
+    if horizontal >= u32::from(area.width) || vertical >= u32::from(area.height) {
         return Vec::new();
     }
 
-    let cols = (n as f64).sqrt().ceil() as u16;
-    let rows = ((n as f64) / f64::from(cols)).ceil() as u16;
-
-    let row_constraints: Vec<Constraint> =
-        std::iter::repeat_n(Constraint::Ratio(1, rows.into()), rows as usize).collect();
+    let inner = new_rect(
+        area.x + left,
+        area.y + top,
+        area.width - left - right,
+        area.height - top - bottom,
+    );
+    auto_grid(inner, n, spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout above a footer of `footer_height` rows.
+///
+/// The footer spans the bottom of `area`, and the grid fills the rest with `spacing` between its
+/// last row and the footer. A footer taller than `area` takes all of it, and the cells all come
+/// back as zero-size rects at the origin of `area`. A footer height of 0 leaves the whole area to
+/// the grid, with an empty footer on its bottom edge.
+///
+/// # Returns
+///
+/// The `n` cells of the grid and the footer.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_with_footer;
+///
+/// let (cells, footer) = auto_grid_with_footer(Rect::new(0, 0, 40, 20), 4, 1, 3);
+/// assert_eq!(footer, Rect::new(0, 17, 40, 3));
+/// assert_eq!(cells[3], Rect::new(21, 9, 19, 7));
+/// assert_eq!(footer.y - cells[3].bottom(), 1);
+/// ```
+pub fn auto_grid_with_footer(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    footer_height: u16,
+) -> (Vec<Rect>, Rect) {
+    let footer_height = footer_height.min(area.height);
+    let footer = Rect {
+        y: area.bottom() - footer_height,
+        height: footer_height,
+        ..area
+    };
+
+    let gap = if footer_height > 0 { spacing } else { 0 };
+    let grid = Rect {
+        height: (area.height - footer_height).saturating_sub(gap),
+        ..area
+    };
+    let mut cells = auto_grid(grid, n, spacing);
+    collapse_empty(area, &mut cells);
+    (cells, footer)
+}
+
+/// Arranges `n` items in an automatic grid layout within the top-left part of `area`.
+///
+/// The grid gets the top-left `h_pct` percent of `area.width` and `v_pct` percent of
+/// `area.height`, both rounded down. Percentages above 100 are treated as 100. The rest of the
+/// width, to the right of the grid, is returned for other content and spans the full height of
+/// `area`; the space below the grid, if any, is left unused. There is no spacing between the grid
+/// and the leftover rect.
+///
+/// # Returns
+///
+/// The `n` cells of the grid and the leftover rect on its right.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_in_fraction;
+///
+/// let (cells, detail) = auto_grid_in_fraction(Rect::new(0, 0, 100, 20), 4, 0, 70, 100);
+/// assert_eq!(cells[3], Rect::new(35, 10, 35, 10));
+/// assert_eq!(detail, Rect::new(70, 0, 30, 20));
+/// ```
+pub fn auto_grid_in_fraction(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    h_pct: u8,
+    v_pct: u8,
+) -> (Vec<Rect>, Rect) {
+    let fraction =
+        |length: u16, pct: u8| (u32::from(length) * u32::from(pct.min(100)) / 100) as u16;
+    let grid = Rect {
+        width: fraction(area.width, h_pct),
+        height: fraction(area.height, v_pct),
+        ..area
+    };
+    let leftover = Rect {
+        x: grid.right(),
+        width: area.width - grid.width,
+        ..area
+    };
+
+    let mut cells = auto_grid(grid, n, spacing);
+    collapse_empty(area, &mut cells);
+    (cells, leftover)
+}
+
+/// A row of [`auto_grid_rows_spec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowSpec {
+    /// A single full-width rect with a fixed height.
+    Fixed(u16),
+    /// An automatic grid of the given number of items, sharing the remaining height with the
+    /// other grid rows.
+    Grid(usize),
+}
+
+/// Stacks fixed-height rows and automatic grids from top to bottom within `area`.
+///
+/// `spacing` separates the rows, and the cells within each grid. The fixed rows get their height
+/// first, and the grid rows share what is left equally. When the fixed rows don't fit, ratatui's
+/// `Layout` solver shrinks them.
+///
+/// # Returns
+///
+/// One vector per row spec, in order: a single rect for a [`RowSpec::Fixed`] row, and the cells
+/// of [`auto_grid`] for a [`RowSpec::Grid`] row.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{RowSpec, auto_grid_rows_spec};
+///
+/// let rows = auto_grid_rows_spec(
+///     Rect::new(0, 0, 40, 20),
+///     0,
+///     &[RowSpec::Fixed(3), RowSpec::Grid(4), RowSpec::Fixed(1)],
+/// );
+/// assert_eq!(rows[0], [Rect::new(0, 0, 40, 3)]);
+/// assert_eq!(rows[1][3], Rect::new(20, 11, 20, 8));
+/// assert_eq!(rows[2], [Rect::new(0, 19, 40, 1)]);
+/// ```
+pub fn auto_grid_rows_spec(area: Rect, spacing: u16, rows: &[RowSpec]) -> Vec<Vec<Rect>> {
+    let constraints = rows.iter().map(|row| match *row {
+        RowSpec::Fixed(height) => Constraint::Length(height),
+        RowSpec::Grid(_) => Constraint::Fill(1),
+    });
+    let bands = Layout::vertical(constraints).spacing(spacing).split(area);
+
+    rows.iter()
+        .zip(bands.iter())
+        .map(|(row, &band)| match *row {
+            RowSpec::Fixed(_) => vec![band],
+            RowSpec::Grid(n) => auto_grid(band, n, spacing),
+        })
+        .collect()
+}
+
+/// Arranges `n` items in an automatic grid layout with cell positions snapped to multiples of
+/// `step`.
+///
+/// The grid is computed like [`auto_grid`], then the `x` and `y` of every cell are rounded to the
+/// nearest multiple of `step`, in the coordinates of the terminal rather than of `area`. A cell
+/// never starts before `area`, so the first column and row round up when `area` doesn't start on
+/// a multiple. The far edge of each cell stays where it was, unless that would leave less than
+/// `spacing` before the next cell, in which case the cell is shortened: the gaps between cells are
+/// at least `spacing`, and up to `step - 1` wider. Cells that lose all their room come back as
+/// zero-size rects at the origin of `area`, which may not be on a multiple of `step`.
+///
+/// A `step` of 0 or 1 gives the same cells as [`auto_grid`].
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_snapped;
+///
+/// // Without snapping, the second cell would start at 51.
+/// let cells = auto_grid_snapped(Rect::new(0, 0, 100, 10), 2, 1, 4);
+/// assert_eq!(cells[0], Rect::new(0, 0, 50, 10));
+/// assert_eq!(cells[1], Rect::new(52, 0, 48, 10));
+/// ```
+pub fn auto_grid_snapped(area: Rect, n: usize, spacing: u16, step: u16) -> Vec<Rect> {
+    if step <= 1 || n == 0 || area.is_empty() {
+        return auto_grid(area, n, spacing);
+    }
+
+    let (rows, cols) = dimensions(n);
+    let columns = snapped_axis(area.x, area.width, cols, spacing, step);
+
+    let mut out = Vec::with_capacity(n);
+    for (y, height) in snapped_axis(area.y, area.height, rows, spacing, step) {
+        out.extend(
+            columns
+                .iter()
+                .map(|&(x, width)| new_rect(x, y, width, height)),
+        );
+    }
+    out.resize(n, empty_cell(area));
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Arranges `n` items in an automatic grid layout, also returning the empty trailing slots.
+///
+/// # Returns
+///
+/// The cells of [`auto_grid`], and the empty slots left at the end of a partial last row, in
+/// row-major order. Together they tile the full grid.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_full;
+///
+/// let area = Rect::new(0, 0, 90, 60);
+/// let (populated, empty) = auto_grid_full(area, 5, 0);
+/// assert_eq!(populated.len(), 5);
+/// assert_eq!(empty, vec![Rect::new(60, 30, 30, 30)]);
+/// ```
+pub fn auto_grid_full(area: Rect, n: usize, spacing: u16) -> (Vec<Rect>, Vec<Rect>) {
+    if n == 0 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let (rows, cols) = dimensions(n);
+    let mut populated = split_grid(area, rows, cols, spacing, spacing);
+    collapse_empty(area, &mut populated);
+    let empty = populated.split_off(n.min(populated.len()));
+    populated.resize(n, empty_cell(area));
+    (populated, empty)
+}
+
+/// Arranges `n` items in an automatic grid layout, returning the row and column of every cell.
+///
+/// The cells are the ones of [`auto_grid`], each with its `(row, col)` in the grid. Items past
+/// [`MAX_CELLS`] have no slot in the grid and get `(u16::MAX, u16::MAX)`, which no slot uses.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_enumerate;
+///
+/// let cells = auto_grid_enumerate(Rect::new(0, 0, 90, 60), 5, 0);
+/// assert_eq!(cells[4], (1, 1, Rect::new(30, 30, 30, 30)));
+/// ```
+pub fn auto_grid_enumerate(area: Rect, n: usize, spacing: u16) -> Vec<(u16, u16, Rect)> {
+    let cols = usize::from(dimensions(n).1.max(1));
+    auto_grid(area, n, spacing)
+        .into_iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            if index < MAX_CELLS {
+                ((index / cols) as u16, (index % cols) as u16, cell)
+            } else {
+                (u16::MAX, u16::MAX, cell)
+            }
+        })
+        .collect()
+}
+
+/// Arranges `n` items in an automatic grid layout, returning the cells grouped into rows.
+///
+/// The cells are the ones of [`auto_grid`], with one vector per row from top to bottom. Every row
+/// has as many cells as the grid has columns, except for a partial last row, so flattening the
+/// rows gives the output of [`auto_grid`] back.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_grouped;
+///
+/// let rows = auto_grid_grouped(Rect::new(0, 0, 90, 60), 5, 0);
+/// assert_eq!(rows.len(), 2);
+/// assert_eq!(rows[1], [Rect::new(0, 30, 30, 30), Rect::new(30, 30, 30, 30)]);
+/// ```
+pub fn auto_grid_grouped(area: Rect, n: usize, spacing: u16) -> Vec<Vec<Rect>> {
+    let cols = usize::from(dimensions(n).1.max(1));
+    auto_grid(area, n, spacing)
+        .chunks(cols)
+        .map(<[Rect]>::to_vec)
+        .collect()
+}
+
+/// Arranges `n` items in an automatic grid layout, returning the rect covered by the cells.
+///
+/// The bounds are the smallest rect enclosing every non-empty cell. They usually match `area`, but
+/// are smaller when there are fewer cells than tracks can fit, for example when the spacing leaves
+/// room for only some of the columns. When every cell is empty, the bounds are a zero-size rect at
+/// the origin of `area`.
+///
+/// # Returns
+///
+/// The `n` cells of [`auto_grid`] and their bounds.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_bounds;
+///
+/// // The spacing only leaves room for 1 of 3 columns.
+/// let (cells, bounds) = auto_grid_bounds(Rect::new(0, 0, 5, 9), 9, 2);
+/// assert_eq!(bounds, Rect::new(0, 0, 1, 9));
+/// assert_eq!(bounds, cells[0].union(cells[6]));
+/// ```
+pub fn auto_grid_bounds(area: Rect, n: usize, spacing: u16) -> (Vec<Rect>, Rect) {
+    let cells = auto_grid(area, n, spacing);
+    let bounds = cells
+        .iter()
+        .filter(|cell| !cell.is_empty())
+        .copied()
+        .reduce(Rect::union)
+        .unwrap_or(empty_cell(area));
+    (cells, bounds)
+}
+
+/// Arranges `n` items in an automatic grid layout where neighboring cells overlap.
+///
+/// The grid is computed like [`auto_grid`] without spacing, then every cell is extended by
+/// `overlap` to the right and to the bottom, so that it overlaps the next cell of its row and the
+/// cell below it by that amount, like a negative spacing. Cells are cut at the edges of `area`, so
+/// the cells of the last column and row don't grow and the grid still spans exactly `area`.
+/// Zero-size cells stay as they are.
+///
+/// Since cells overlap, the ones rendered later are drawn over the earlier ones.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_overlap;
+///
+/// let cells = auto_grid_overlap(Rect::new(0, 0, 40, 10), 2, 3);
+/// assert_eq!(cells[0], Rect::new(0, 0, 23, 10));
+/// assert_eq!(cells[1], Rect::new(20, 0, 20, 10));
+/// ```
+pub fn auto_grid_overlap(area: Rect, n: usize, overlap: u16) -> Vec<Rect> {
+    let mut out = auto_grid(area, n, 0);
+    for cell in out.iter_mut().filter(|cell| !cell.is_empty()) {
+        cell.width = cell
+            .width
+            .saturating_add(overlap)
+            .min(area.right() - cell.x);
+        cell.height = cell
+            .height
+            .saturating_add(overlap)
+            .min(area.bottom() - cell.y);
+    }
+    out
+}
+
+/// Returns the largest rect with an aspect ratio of `aspect_w:aspect_h` centered in `cell`.
+///
+/// The ratio is in terminal cells, so `4:3` gives 4 columns for every 3 rows. The inner rect is
+/// rounded down to whole cells, and the leftover space is split evenly on both sides, the extra
+/// cell going to the right or bottom. If `cell` is too small for a 1×1 rect with that ratio, or if
+/// the ratio has a zero term, this returns a zero-size rect at the origin of `cell`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::letterbox;
+///
+/// assert_eq!(letterbox(Rect::new(0, 0, 40, 10), 4, 3), Rect::new(13, 0, 13, 10));
+/// assert_eq!(letterbox(Rect::new(0, 0, 8, 20), 4, 3), Rect::new(0, 7, 8, 6));
+/// ```
+pub fn letterbox(cell: Rect, aspect_w: u16, aspect_h: u16) -> Rect {
+    let (aspect_w, aspect_h) = (u32::from(aspect_w), u32::from(aspect_h));
+    let (width, height) = (u32::from(cell.width), u32::from(cell.height));
+    if aspect_w == 0 || aspect_h == 0 {
+        return empty_cell(cell);
+    }
+
+    let (width, height) = if width * aspect_h <= height * aspect_w {
+        (width, width * aspect_h / aspect_w)
+    } else {
+        (height * aspect_w / aspect_h, height)
+    };
+    if width == 0 || height == 0 {
+        return empty_cell(cell);
+    }
+
+    let (width, height) = (width as u16, height as u16);
+    new_rect(
+        cell.x + (cell.width - width) / 2,
+        cell.y + (cell.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Arranges `n` items with [`auto_grid`], pairing each cell with its [`letterbox`] for the
+/// `(width, height)` aspect ratio.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_letterboxed;
+///
+/// let cells = auto_grid_letterboxed(Rect::new(0, 0, 80, 10), 2, 0, (4, 3));
+/// assert_eq!(cells[1], (Rect::new(40, 0, 40, 10), Rect::new(53, 0, 13, 10)));
+/// ```
+pub fn auto_grid_letterboxed(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    (aspect_w, aspect_h): (u16, u16),
+) -> Vec<(Rect, Rect)> {
+    auto_grid(area, n, spacing)
+        .into_iter()
+        .map(|cell| (cell, letterbox(cell, aspect_w, aspect_h)))
+        .collect()
+}
+
+/// Arranges `n` items in an automatic grid layout where every cell has the same area.
+///
+/// With [`auto_grid`], a partial last row keeps the cell size of the full rows and leaves empty
+/// slots at the end. Here the cells of the last row are stretched to the full width instead, and
+/// the row heights are adjusted so that the area of every cell stays the same: full rows take a
+/// `cols / n` share of the height and the last row a `remaining / n` share.
+///
+/// The compromise is that the cells no longer share a shape: the last row is wider and shorter
+/// than the others, and the full rows get taller to make up for it. The areas are only equal up to
+/// the rounding of each track to whole cells, and ignoring the spacing. When `n` is a multiple of
+/// the number of columns, this is the same as [`auto_grid`].
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_equal_area;
+///
+/// let area = Rect::new(0, 0, 120, 70);
+/// let cells = auto_grid_equal_area(area, 5, 0);
+/// assert_eq!(cells[0], Rect::new(0, 0, 40, 42));
+/// assert_eq!(cells[3], Rect::new(0, 42, 60, 28));
+/// assert_eq!(cells[0].area(), cells[3].area());
+/// ```
+pub fn auto_grid_equal_area(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let (rows, cols) = dimensions(n);
+    let remaining = n % usize::from(cols.max(1));
+    if remaining == 0 || n > MAX_CELLS {
+        return auto_grid(area, n, spacing);
+    }
+    if area.is_empty() {
+        return vec![empty_cell(area); n];
+    }
+
+    let total = n as u32;
+    let mut row_constraints = vec![Constraint::Ratio(cols.into(), total); usize::from(rows - 1)];
+    row_constraints.push(Constraint::Ratio(remaining as u32, total));
+
+    let row_areas = Layout::vertical(row_constraints)
+        .spacing(spacing)
+        .split(area);
+    let columns: Vec<_> = split_axis(area.x, area.width, cols, spacing).collect();
+    let last_columns: Vec<_> = split_axis(area.x, area.width, remaining as u16, spacing).collect();
+
+    let mut out = Vec::with_capacity(n);
+    for (index, row_area) in row_areas.iter().enumerate() {
+        let columns = if index + 1 == row_areas.len() {
+            &last_columns
+        } else {
+            &columns
+        };
+        out.extend(
+            columns
+                .iter()
+                .map(|&(x, width)| new_rect(x, row_area.y, width, row_area.height)),
+        );
+    }
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Computes the separator lines between the cells of [`auto_grid`], to draw borders in the
+/// spacing.
+///
+/// Each separator is 1 cell thick and sits in the middle of the spacing between two columns or
+/// two rows. Vertical separators run from the top of the grid to the bottom of the last row with
+/// a cell on both sides, so they stop above the empty slots of a partial last row. Horizontal
+/// separators span the width of `area`.
+///
+/// The spacing has to be at least 1 for separators to fit, so a `spacing` of 0 gives none.
+///
+/// # Returns
+///
+/// The vertical separators from left to right, one per pair of adjacent columns, and the
+/// horizontal separators from top to bottom, one per pair of adjacent rows.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_separators;
+///
+/// let area = Rect::new(0, 0, 21, 11);
+/// let (vertical, horizontal) = auto_grid_separators(area, 3, 1);
+/// // The second row only has a cell on the left.
+/// assert_eq!(vertical, vec![Rect::new(10, 0, 1, 5)]);
+/// assert_eq!(horizontal, vec![Rect::new(0, 5, 21, 1)]);
+/// ```
+pub fn auto_grid_separators(area: Rect, n: usize, spacing: u16) -> (Vec<Rect>, Vec<Rect>) {
+    if n == 0 || spacing == 0 || area.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    // Items past `MAX_CELLS` have no slot in the grid, so they don't extend the separators.
+    let n = n.min(MAX_CELLS);
+    let (rows, cols) = dimensions(n);
+    let columns: Vec<_> = split_axis(area.x, area.width, cols, spacing).collect();
+    let rows: Vec<_> = split_axis(area.y, area.height, rows, spacing).collect();
+
+    // Returns the position of the separator after each track, except the last one.
+    let gaps = |tracks: &[(u16, u16)], end: u16| {
+        tracks[..tracks.len() - 1]
+            .iter()
+            .map(|&(position, size)| position + size + (spacing - 1) / 2)
+            .filter(|&gap| gap < end)
+            .collect::<Vec<_>>()
+    };
+
+    let cols = usize::from(cols);
+    let vertical = gaps(&columns, area.right())
+        .into_iter()
+        .enumerate()
+        .map(|(col, x)| {
+            // The last row with a cell on the right of this separator.
+            let row = (n - (col + 2)) / cols;
+            let (y, height) = rows[row];
+            new_rect(x, area.y, 1, y + height - area.y)
+        })
+        .collect();
+    let horizontal = gaps(&rows, area.bottom())
+        .into_iter()
+        .map(|y| new_rect(area.x, y, area.width, 1))
+        .collect();
+    (vertical, horizontal)
+}
+
+/// Computes the band of each row of [`auto_grid`], for striping or highlighting whole rows.
+///
+/// A band has the `y` and height of the cells of its row, and spans from the left edge of the
+/// first column to the right edge of the last one, covering the spacing between the columns. The
+/// band of a partial last row spans the same width as the full rows. Rows without room come back
+/// as zero-size rects at the origin of `area`, like their cells.
+///
+/// # Returns
+///
+/// One band per row, from top to bottom.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_rows;
+///
+/// let bands = auto_grid_rows(Rect::new(0, 0, 21, 11), 3, 1);
+/// assert_eq!(bands, [Rect::new(0, 0, 21, 5), Rect::new(0, 6, 21, 5)]);
+/// ```
+pub fn auto_grid_rows(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let (rows, cols) = dimensions(n);
+    let span = tracks_span(area.x, area.width, cols, spacing);
+    split_axis(area.y, area.height, rows, spacing)
+        .map(|(y, height)| match span {
+            Some((x, width)) if height > 0 => new_rect(x, y, width, height),
+            _ => empty_cell(area),
+        })
+        .collect()
+}
+
+/// Computes the band of each column of [`auto_grid`], for highlighting whole columns.
+///
+/// A band has the `x` and width of the cells of its column, and spans from the top edge of the
+/// first row to the bottom edge of the last one, covering the spacing between the rows. The bands
+/// of the columns without a cell in a partial last row still reach the bottom of the grid. The
+/// intersection of a band of [`auto_grid_rows`] and a band of this function is the cell of that
+/// row and column. Columns without room come back as zero-size rects at the origin of `area`,
+/// like their cells.
+///
+/// # Returns
+///
+/// One band per column, from left to right.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_columns;
+///
+/// let bands = auto_grid_columns(Rect::new(0, 0, 21, 11), 3, 1);
+/// assert_eq!(bands, [Rect::new(0, 0, 10, 11), Rect::new(11, 0, 10, 11)]);
+/// ```
+pub fn auto_grid_columns(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let (rows, cols) = dimensions(n);
+    let span = tracks_span(area.y, area.height, rows, spacing);
+    split_axis(area.x, area.width, cols, spacing)
+        .map(|(x, width)| match span {
+            Some((y, height)) if width > 0 => new_rect(x, y, width, height),
+            _ => empty_cell(area),
+        })
+        .collect()
+}
+
+/// Splits `area` into a 2×2 grid.
+///
+/// Returns the top-left, top-right, bottom-left and bottom-right cells, in that order. This is
+/// the same as `auto_grid(area, 4, spacing)`, as a fixed-size array.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::quadrants;
+///
+/// let [top_left, top_right, bottom_left, bottom_right] = quadrants(Rect::new(0, 0, 100, 50), 0);
+/// assert_eq!(top_left, Rect::new(0, 0, 50, 25));
+/// assert_eq!(top_right, Rect::new(50, 0, 50, 25));
+/// assert_eq!(bottom_left, Rect::new(0, 25, 50, 25));
+/// assert_eq!(bottom_right, Rect::new(50, 25, 50, 25));
+/// ```
+pub fn quadrants(area: Rect, spacing: u16) -> [Rect; 4] {
+    auto_grid_areas(area, spacing)
+}
+
+/// Arranges `N` items in an automatic grid layout, returned as a fixed-size array.
+///
+/// This is the same as `auto_grid(area, N, spacing)`, for destructuring the cells like with
+/// ratatui's `Layout::areas`. The cells are those of a grid, not of a layout along one axis: when
+/// `N` is not a multiple of the number of columns, the cells of the last row keep the width of the
+/// others instead of spanning the row.
+///
+/// # Panics
+///
+/// Panics if the grid doesn't have exactly `N` cells, like `Layout::areas`. This never happens
+/// since [`auto_grid`] always returns one cell per item.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_areas;
+///
+/// // 3 items in a 2×2 grid: the last one keeps the size of the others and doesn't span the
+/// // bottom row.
+/// let [left, right, bottom_left] = auto_grid_areas(Rect::new(0, 0, 30, 30), 0);
+/// assert_eq!(left, Rect::new(0, 0, 15, 15));
+/// assert_eq!(right, Rect::new(15, 0, 15, 15));
+/// assert_eq!(bottom_left, Rect::new(0, 15, 15, 15));
+/// ```
+pub fn auto_grid_areas<const N: usize>(area: Rect, spacing: u16) -> [Rect; N] {
+    auto_grid(area, N, spacing)
+        .try_into()
+        .unwrap_or_else(|cells: Vec<Rect>| panic!("expected {N} cells, got {}", cells.len()))
+}
+
+/// Splits `area` into a single row of `n` side-by-side cells, left to right.
+///
+/// The cells are sized like the columns of [`auto_grid`], without picking a grid shape.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::columns;
+///
+/// let cells = columns(Rect::new(0, 0, 90, 10), 3, 0);
+/// assert_eq!(cells[1], Rect::new(30, 0, 30, 10));
+/// ```
+pub fn columns(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let tracks = n.min(usize::from(u16::MAX)) as u16;
+    grid_cells(area, n, (1, tracks), spacing, spacing)
+}
+
+/// Splits `area` into a single column of `n` stacked cells, top to bottom.
+///
+/// The cells are sized like the rows of [`auto_grid`], without picking a grid shape.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::rows;
+///
+/// let cells = rows(Rect::new(0, 0, 10, 90), 3, 0);
+/// assert_eq!(cells[1], Rect::new(0, 30, 10, 30));
+/// ```
+pub fn rows(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    let tracks = n.min(usize::from(u16::MAX)) as u16;
+    grid_cells(area, n, (tracks, 1), spacing, spacing)
+}
+
+/// Arranges `n` items in an automatic grid layout positioned at `(0, 0)`.
+///
+/// The cells only depend on the size of the area, so they can be computed once and translated to
+/// the actual position of the area later by adding `area.x` and `area.y` to every cell.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, auto_grid_normalized};
+///
+/// let area = Rect::new(10, 5, 100, 100);
+/// let cells: Vec<Rect> = auto_grid_normalized(100, 100, 9, 1)
+///     .into_iter()
+///     .map(|cell| Rect { x: cell.x + area.x, y: cell.y + area.y, ..cell })
+///     .collect();
+/// assert_eq!(cells, auto_grid(area, 9, 1));
+/// ```
+pub fn auto_grid_normalized(width: u16, height: u16, n: usize, spacing: u16) -> Vec<Rect> {
+    auto_grid(new_rect(0, 0, width, height), n, spacing)
+}
+
+/// The heuristic used to pick the dimensions of a grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DimensionHint {
+    /// Computes columns as √n (rounded up) and rows as n/cols (rounded up), like [`auto_grid`].
+    #[default]
+    ColumnsFirst,
+    /// Computes rows as √n (rounded up) and columns as n/rows (rounded up).
+    ///
+    /// This is the transpose of [`DimensionHint::ColumnsFirst`]: both give a 3×3 grid for `n = 8`,
+    /// but for `n = 6` it gives 3 rows of 2 columns instead of 2 rows of 3 columns.
+    RowsFirst,
+    /// Picks the shape with the fewest empty trailing slots, among the shapes no more than twice as
+    /// long on one side as on the other.
+    ///
+    /// Ties go to the squarest shape, then to the one with more columns. For `n = 7` this gives 2
+    /// rows of 4 columns (1 empty slot) instead of 3 rows of 3 columns (2 empty slots).
+    MinimizeWaste,
+}
+
+/// Returns the `(rows, cols)` of the grid used for `n` items with the given heuristic.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_auto_grid::{DimensionHint, grid_shape};
+///
+/// assert_eq!(grid_shape(6, DimensionHint::ColumnsFirst), (2, 3));
+/// assert_eq!(grid_shape(6, DimensionHint::RowsFirst), (3, 2));
+/// ```
+pub fn grid_shape(n: usize, hint: DimensionHint) -> (u16, u16) {
+    if n == 0 {
+        return (0, 0);
+    }
+
+    let (rows, cols) = dimensions(n);
+    match hint {
+        DimensionHint::ColumnsFirst => (rows, cols),
+        DimensionHint::RowsFirst => (cols, rows),
+        DimensionHint::MinimizeWaste => min_waste_dimensions(n).unwrap_or((rows, cols)),
+    }
+}
+
+/// Arranges `n` items in a grid layout whose dimensions are picked with the given heuristic.
+///
+/// Cells are returned in row-major order.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{DimensionHint, auto_grid_with_hint};
+///
+/// let area = Rect::new(0, 0, 100, 90);
+/// // 3 rows of 2 columns.
+/// let cells = auto_grid_with_hint(area, 6, 0, DimensionHint::RowsFirst);
+/// assert_eq!(cells[1], Rect::new(50, 0, 50, 30));
+/// assert_eq!(cells[2], Rect::new(0, 30, 50, 30));
+/// ```
+pub fn auto_grid_with_hint(area: Rect, n: usize, spacing: u16, hint: DimensionHint) -> Vec<Rect> {
+    grid_cells(area, n, grid_shape(n, hint), spacing, spacing)
+}
+
+/// Returns the index of the first cell containing the position `(x, y)`.
+///
+/// Returns `None` if the position doesn't fall in any cell, for example when it lands in the
+/// spacing between cells. Zero-size cells never contain any position.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, hit_test};
+///
+/// let cells = auto_grid(Rect::new(0, 0, 100, 100), 4, 2);
+/// assert_eq!(hit_test(&cells, 80, 20), Some(1));
+/// assert_eq!(hit_test(&cells, 50, 20), None);
+/// ```
+pub fn hit_test(cells: &[Rect], x: u16, y: u16) -> Option<usize> {
+    hit_test_nearest(cells, x, y, 0)
+}
+
+/// Returns the index of the cell closest to the position `(x, y)`, if it is at most
+/// `max_distance` away.
+///
+/// The distance to a cell is the larger of the horizontal and vertical distances to its nearest
+/// edge, so a position in the spacing between cells snaps to a neighbouring cell when the spacing
+/// is narrow enough. Between cells at the same distance, the first one wins, which is the top
+/// then the left one for cells in row-major order. A `max_distance` of 0 gives the same result as
+/// [`hit_test`]. Zero-size cells are never hit.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, hit_test_nearest};
+///
+/// let cells = auto_grid(Rect::new(0, 0, 100, 100), 4, 2);
+/// // The spacing between the first two columns is at 49 and 50.
+/// assert_eq!(hit_test_nearest(&cells, 49, 20, 1), Some(0));
+/// assert_eq!(hit_test_nearest(&cells, 50, 20, 1), Some(1));
+/// assert_eq!(hit_test_nearest(&cells, 50, 20, 0), None);
+/// ```
+pub fn hit_test_nearest(cells: &[Rect], x: u16, y: u16, max_distance: u16) -> Option<usize> {
+    let mut nearest = None;
+    for (index, cell) in cells.iter().enumerate() {
+        let Some(distance) = distance(*cell, x, y) else {
+            continue;
+        };
+        if distance == 0 {
+            return Some(index);
+        }
+        if distance <= max_distance && nearest.is_none_or(|(_, best)| distance < best) {
+            nearest = Some((index, distance));
+        }
+    }
+    nearest.map(|(index, _)| index)
+}
+
+/// Returns the distance from the position `(x, y)` to `cell`, or `None` if the cell is empty.
+fn distance(cell: Rect, x: u16, y: u16) -> Option<u16> {
+    if cell.is_empty() {
+        return None;
+    }
+
+    let axis = |position: u16, start: u16, end: u16| {
+        if position < start {
+            start - position
+        } else {
+            position.saturating_sub(end - 1)
+        }
+    };
+    Some(axis(x, cell.x, cell.right()).max(axis(y, cell.y, cell.bottom())))
+}
+
+/// Computes the `(rows, cols)` of the grid used for `n` items.
+///
+/// `n` is clamped to [`MAX_CELLS`], so both dimensions always fit in a `u16`.
+fn dimensions(n: usize) -> (u16, u16) {
+    let n = n.min(MAX_CELLS);
+    let cols = ceil_sqrt(n);
+    if cols == 0 {
+        return (0, 0);
+    }
+    (n.div_ceil(cols) as u16, cols as u16)
+}
+
+/// Returns √n rounded up, with integer arithmetic only.
+fn ceil_sqrt(n: usize) -> usize {
+    let root = n.isqrt();
+    root + usize::from(root * root < n)
+}
+
+/// Computes the `(rows, cols)` of the grid used for `n` items, rounding the columns as given.
+fn rounded_dimensions(n: usize, rounding: Rounding) -> (u16, u16) {
+    let n = n.min(MAX_CELLS);
+    let root = n.isqrt();
+    let cols = match rounding {
+        Rounding::Ceil => ceil_sqrt(n),
+        Rounding::Floor => root,
+        // √n is below root + ½ as long as n is below root² + root + ¼.
+        Rounding::Nearest => root + usize::from(n - root * root > root),
+    };
+    let cols = cols.max(1);
+    let rows = n.div_ceil(cols).min(usize::from(u16::MAX));
+    (rows as u16, cols as u16)
+}
+
+/// Returns the `(rows, cols)` of [`DimensionHint::MinimizeWaste`], or `None` if no shape fits in
+/// `u16` dimensions.
+fn min_waste_dimensions(n: usize) -> Option<(u16, u16)> {
+    let max = usize::from(u16::MAX);
+    let low = (n / 2).isqrt().max(1);
+    let high = (2 * n.min(MAX_CELLS)).isqrt().saturating_add(1).min(max);
+
+    (low..=high)
+        .filter_map(|cols| {
+            let rows = n.div_ceil(cols);
+            let fits = rows <= max && rows <= 2 * cols && cols <= 2 * rows;
+            fits.then_some((rows, cols))
+        })
+        // Fewest empty slots, then squarest, then most columns.
+        .min_by_key(|&(rows, cols)| (rows * cols - n, rows.abs_diff(cols), usize::MAX - cols))
+        .map(|(rows, cols)| (rows as u16, cols as u16))
+}
+
+/// Splits an axis into `tracks` tracks like [`split_axis`], then snaps the start of every track to
+/// a multiple of `step`, shortening tracks to keep `spacing` before the next one.
+fn snapped_axis(start: u16, length: u16, tracks: u16, spacing: u16, step: u16) -> Vec<(u16, u16)> {
+    let (step, end) = (u32::from(step), u32::from(start) + u32::from(length));
+    let first = u32::from(start).div_ceil(step) * step;
+    let snap = |position: u16| {
+        let rounded = (u32::from(position) + step / 2) / step * step;
+        rounded.clamp(first, end.max(first))
+    };
+
+    let tracks: Vec<(u32, u32)> = split_axis(start, length, tracks, spacing)
+        .map(|(position, size)| (snap(position), u32::from(position) + u32::from(size)))
+        .collect();
+
+    let mut out = Vec::with_capacity(tracks.len());
+    for (i, &(position, far)) in tracks.iter().enumerate() {
+        let limit = match tracks.get(i + 1) {
+            Some(&(next, _)) => next.saturating_sub(u32::from(spacing)),
+            None => end,
+        };
+        let far = far.min(limit).min(end);
+        let position = position.min(end);
+        out.push((position as u16, far.saturating_sub(position) as u16));
+    }
+    out
+}
+
+/// Returns the size of the smallest of `tracks` tracks sharing `length` with `spacing` between them.
+fn min_track_size(length: u16, tracks: u16, spacing: u16) -> u16 {
+    if tracks == 0 {
+        return length;
+    }
+
+    let gaps = u32::from(spacing) * u32::from(tracks - 1);
+    (u32::from(length).saturating_sub(gaps) / u32::from(tracks)) as u16
+}
+
+/// Returns the size of the largest of `tracks` tracks sharing `length` with `spacing` between them,
+/// at most one more than [`min_track_size`].
+fn max_track_size(length: u16, tracks: u16, spacing: u16) -> u16 {
+    if tracks == 0 {
+        return length;
+    }
+
+    let gaps = u32::from(spacing) * u32::from(tracks - 1);
+    u32::from(length)
+        .saturating_sub(gaps)
+        .div_ceil(u32::from(tracks)) as u16
+}
+
+/// Returns the width of the widest cells of a `rows`×`cols` grid divided by the height of the
+/// shortest ones, or `None` if some cells get no width.
+///
+/// This is how both [`auto_grid_max_ratio`] and [`grid_fits`] measure `max_ratio`.
+fn cell_ratio(area: Rect, (rows, cols): (u16, u16), spacing: u16) -> Option<f32> {
+    if min_track_size(area.width, cols, spacing) == 0 {
+        return None;
+    }
+    let width = max_track_size(area.width, cols, spacing);
+    let height = min_track_size(area.height, rows, spacing);
+    Some(f32::from(width) / f32::from(height))
+}
+
+/// Returns how many tracks of at least `min` fit in `length` with `spacing` between them, at least 1.
+fn fitting_tracks(length: u16, min: u16, spacing: u16) -> u16 {
+    let fit =
+        (u32::from(length) + u32::from(spacing)) / (u32::from(min) + u32::from(spacing)).max(1);
+    fit.clamp(1, u32::from(u16::MAX)) as u16
+}
+
+/// Converts a percentage of `length` into spacing between `tracks` tracks, making sure each track
+/// keeps at least one cell.
+fn pct_spacing(length: u16, tracks: u16, pct: u8) -> u16 {
+    if tracks <= 1 {
+        return 0;
+    }
+
+    let spacing = u32::from(length) * u32::from(pct.min(100)) / 100;
+    let max = u32::from(length.saturating_sub(tracks)) / u32::from(tracks - 1);
+    spacing.min(max) as u16
+}
+
+/// Returns the spacing between `tracks` tracks of `size` that fills `length`, if they fit.
+fn fit_spacing(length: u16, tracks: u16, size: u16) -> Option<u16> {
+    let used = u32::from(tracks) * u32::from(size);
+    let leftover = u32::from(length).checked_sub(used)?;
+    if tracks <= 1 {
+        return Some(0);
+    }
+    Some((leftover / u32::from(tracks - 1)) as u16)
+}
+
+/// Returns the `(position, length)` spanned by the tracks of [`split_axis`] that have room, from
+/// the start of the first one to the end of the last one.
+fn tracks_span(start: u16, length: u16, tracks: u16, spacing: u16) -> Option<(u16, u16)> {
+    let mut tracks = split_axis(start, length, tracks, spacing).filter(|&(_, size)| size > 0);
+    let (first, size) = tracks.next()?;
+    let end = tracks
+        .last()
+        .map_or(first + size, |(position, size)| position + size);
+    Some((first, end - first))
+}
+
+/// Returns whether `cell` lies within `area`.
+///
+/// Cells squeezed to zero size by the spacing may sit on the right or bottom edge of `area`, which
+/// is still considered within.
+fn contains(area: Rect, cell: Rect) -> bool {
+    cell.x >= area.x
+        && cell.y >= area.y
+        && cell.right() <= area.right()
+        && cell.bottom() <= area.bottom()
+}
+
+/// Lays out the first `n` cells of a grid with the given `(rows, cols)` in row-major order.
+fn grid_cells(
+    area: Rect,
+    n: usize,
+    (rows, cols): (u16, u16),
+    h_spacing: u16,
+    v_spacing: u16,
+) -> Vec<Rect> {
+    if n == 0 {
+        return Vec::new();
+    }
+    if area.is_empty() {
+        return vec![empty_cell(area); n];
+    }
+
+    let mut out = split_grid(area, rows, cols, h_spacing, v_spacing);
+    out.resize(n, empty_cell(area));
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Returns the zero-size rect used for items that don't get room in `area`.
+fn empty_cell(area: Rect) -> Rect {
+    new_rect(area.x, area.y, 0, 0)
+}
+
+/// Builds a rect from its fields.
+///
+/// `Rect::new` in ratatui 0.28 shrinks rects with an area over `u16::MAX`, which would clip large
+/// cells when building against it.
+fn new_rect(x: u16, y: u16, width: u16, height: u16) -> Rect {
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+/// Moves the cells with a width or height of 0 to the origin of `area`.
+fn collapse_empty(area: Rect, cells: &mut [Rect]) {
+    for cell in cells.iter_mut().filter(|cell| cell.is_empty()) {
+        *cell = empty_cell(area);
+    }
+}
+
+/// Splits `area` into `rows * cols` cells, returned in row-major order.
+fn split_grid(area: Rect, rows: u16, cols: u16, h_spacing: u16, v_spacing: u16) -> Vec<Rect> {
+    let columns: Vec<_> = split_axis(area.x, area.width, cols, h_spacing).collect();
+
+    let mut out = Vec::with_capacity(usize::from(rows) * columns.len());
+    for (y, height) in split_axis(area.y, area.height, rows, v_spacing) {
+        out.extend(
+            columns
+                .iter()
+                .map(|&(x, width)| new_rect(x, y, width, height)),
+        );
+    }
+    out
+}
+
+/// Splits `area` into one cell per row and column constraint, returned in row-major order.
+fn split_constraints(
+    area: Rect,
+    row_constraints: &[Constraint],
+    col_constraints: &[Constraint],
+    h_spacing: u16,
+    v_spacing: u16,
+) -> Vec<Rect> {
+    let row_areas = Layout::vertical(row_constraints)
+        .spacing(v_spacing)
+        .split(area);
+
+    // Every row has the same horizontal extent, so the columns are only solved once and then moved
+    // to each row.
+    let col_areas = Layout::horizontal(col_constraints)
+        .spacing(h_spacing)
+        .split(area);
+
+    let mut out = Vec::with_capacity(row_constraints.len() * col_constraints.len());
+    for row_area in row_areas.iter() {
+        out.extend(col_areas.iter().map(|col_area| Rect {
+            y: row_area.y,
+            height: row_area.height,
+            ..*col_area
+        }));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_area_partial_last_row() {
+        let area = Rect::new(0, 0, 120, 70);
+        let result = auto_grid_equal_area(area, 5, 0);
+        assert_invariants(area, 5, &result);
+
+        assert_ne!(result[3].width, result[0].width);
+        assert_eq!(result[4].right(), area.right());
+        assert!(result.iter().all(|cell| cell.area() == result[0].area()));
+
+        let area = Rect::new(2, 3, 90, 90);
+        let result = auto_grid_equal_area(area, 7, 1);
+        assert_invariants(area, 7, &result);
+        assert_eq!(result[6].width, area.width);
+        assert_eq!(result[6].bottom(), area.bottom());
+    }
+
+    #[test]
+    fn equal_area_full_rows() {
+        let area = Rect::new(0, 0, 100, 100);
+        for n in [1, 4, 6, 9, 12] {
+            assert_eq!(auto_grid_equal_area(area, n, 1), auto_grid(area, n, 1));
+        }
+    }
+
+    #[test]
+    fn empty_grid() {
+        let area = Rect::new(0, 0, 100, 100);
+        let result = auto_grid(area, 0, 0);
+        assert_eq!(result.len(), 0);
+    }
+
+    #[test]
+    fn zero_size_area() {
+        for area in [Rect::new(0, 0, 0, 50), Rect::new(3, 4, 50, 0)] {
+            let result = auto_grid(area, 4, 0);
+            assert_eq!(result, vec![Rect::new(area.x, area.y, 0, 0); 4]);
+        }
+    }
+
+    #[test]
+    fn degenerate_areas() {
+        for area in [Rect::new(5, 5, 0, 10), Rect::new(5, 5, 10, 0), Rect::ZERO] {
+            for n in [1, 2, 3, 7, 16, 100] {
+                for spacing in [0, 1, 5] {
+                    let expected = vec![Rect::new(area.x, area.y, 0, 0); n];
+                    assert_eq!(auto_grid(area, n, spacing), expected, "{area:?}, n {n}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn area_smaller_than_spacing() {
+        let area = Rect::new(5, 5, 3, 3);
+        for n in [2, 4, 9, 20] {
+            let result = auto_grid(area, n, 10);
+            assert_invariants(area, n, &result);
+            // The spacing takes all the room, so every cell is collapsed.
+            assert_eq!(result, vec![Rect::new(5, 5, 0, 0); n]);
+        }
+    }
+
+    #[test]
+    fn areas_near_u16_max() {
+        let max = u16::MAX;
+        for area in [
+            Rect::new(max - 10, max - 10, 10, 10),
+            Rect::new(max - 1, max - 1, 1, 1),
+            Rect::new(max - 200, 0, 200, 7),
+            Rect::new(0, 0, max, max),
+            Rect::new(max, max, 0, 0),
+        ] {
+            for n in [1, 2, 3, 5, 7, 16, 40] {
+                for spacing in [0, 1, 3, 100, max] {
+                    assert_invariants(area, n, &auto_grid(area, n, spacing));
+                    assert_invariants(area, n, &auto_grid_equal_area(area, n, spacing));
+                    assert_invariants(area, n, &columns(area, n, spacing));
+                    assert_invariants(area, n, &rows(area, n, spacing));
+
+                    let (populated, empty) = auto_grid_full(area, n, spacing);
+                    assert_invariants(area, n, &populated);
+                    assert!(empty.iter().all(|&cell| contains(area, cell)));
+                }
+            }
+        }
+    }
+
+    /// Asserts the invariants that hold for any input: exactly `n` cells, all within the area,
+    /// and no overlap between non-empty cells.
+    fn assert_invariants(area: Rect, n: usize, cells: &[Rect]) {
+        assert_eq!(cells.len(), n, "{area:?}");
+        for cell in cells {
+            assert!(contains(area, *cell), "{cell:?} escapes {area:?}");
+        }
+
+        let mut non_empty: Vec<Rect> = cells.iter().copied().filter(|c| !c.is_empty()).collect();
+        non_empty.sort_unstable_by_key(|cell| (cell.y, cell.x));
+        for (i, a) in non_empty.iter().enumerate() {
+            for b in non_empty[i + 1..].iter().take_while(|b| b.y < a.bottom()) {
+                assert!(!a.intersects(*b), "{a:?} overlaps {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn spacing_sweep_stays_in_bounds() {
+        let area = Rect::new(3, 2, 40, 25);
+        for n in [1, 2, 3, 5, 9, 17] {
+            for spacing in 0..=area.width {
+                assert_invariants(area, n, &auto_grid(area, n, spacing));
+            }
+        }
+    }
+
+    #[test]
+    fn more_cells_than_room() {
+        let area = Rect::new(0, 0, 4, 4);
+        let result = auto_grid(area, 100, 0);
+        assert_invariants(area, 100, &result);
+        // 10×10 grid in 4×4 cells: 4 columns and 4 rows get a cell each.
+        assert_eq!(result.iter().filter(|cell| !cell.is_empty()).count(), 16);
+
+        let area = Rect::new(7, 7, 1, 1);
+        let result = auto_grid(area, 10_000, 0);
+        assert_invariants(area, 10_000, &result);
+        assert_eq!(result.iter().filter(|cell| !cell.is_empty()).count(), 1);
+
+        let area = Rect::new(7, 7, 0, 30);
+        let result = auto_grid(area, 10_000, 1);
+        assert_invariants(area, 10_000, &result);
+        assert!(result.iter().all(|&cell| cell == Rect::new(7, 7, 0, 0)));
+    }
+
+    #[test]
+    fn single_cell() {
+        let area = Rect::new(0, 0, 100, 100);
+        let result = auto_grid(area, 1, 0);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], area, "Single cell should fill entire area");
+    }
+
+    #[test]
+    fn four_cells_perfect_square() {
+        let area = Rect::new(0, 0, 100, 100);
+        let result = auto_grid(area, 4, 0);
+
+        assert_eq!(result.len(), 4);
+
+        // Top left
+        assert_eq!(result[0].x, 0);
+        assert_eq!(result[0].y, 0);
+        assert_eq!(result[0].width, 50);
+        assert_eq!(result[0].height, 50);
+
+        // Top right
+        assert_eq!(result[1].x, 50);
+        assert_eq!(result[1].y, 0);
+
+        // Bottom left
+        assert_eq!(result[2].x, 0);
+        assert_eq!(result[2].y, 50);
+
+        // Bottom right
+        assert_eq!(result[3].x, 50);
+        assert_eq!(result[3].y, 50);
+    }
+
+    #[test]
+    fn nine_cells() {
+        let area = Rect::new(0, 0, 99, 99);
+        let result = auto_grid(area, 9, 0);
+
+        assert_eq!(result.len(), 9);
+
+        // first cell
+        assert_eq!(result[0].x, 0);
+        assert_eq!(result[0].y, 0);
+        assert_eq!(result[0].width, 33);
+        assert_eq!(result[0].height, 33);
+
+        // last cell
+        assert_eq!(result[8].x, 66);
+        assert_eq!(result[8].y, 66);
+    }
+
+    #[test]
+    fn non_square_grid() {
+        let area = Rect::new(0, 0, 100, 100);
+        let result = auto_grid(area, 6, 0);
+
+        assert_eq!(result.len(), 6);
+
+        // First row
+        assert_eq!(result[0].y, result[1].y);
+        assert_eq!(result[1].y, result[2].y);
+
+        // Second row
+        assert_eq!(result[3].y, result[4].y);
+        assert_eq!(result[4].y, result[5].y);
+
+        assert_ne!(result[0].y, result[3].y);
+    }
+
+    #[test]
+    fn with_spacing() {
+        let area = Rect::new(0, 0, 100, 100);
+        let result_no_spacing = auto_grid(area, 4, 0);
+        let result_with_spacing = auto_grid(area, 4, 2);
+
+        assert_eq!(result_no_spacing.len(), 4);
+        assert_eq!(result_with_spacing.len(), 4);
+
+        assert!(result_with_spacing[0].width <= result_no_spacing[0].width);
+        assert!(result_with_spacing[0].height <= result_no_spacing[0].height);
+
+        let gap_no_spacing =
+            result_no_spacing[1].x - (result_no_spacing[0].x + result_no_spacing[0].width);
+        let gap_with_spacing =
+            result_with_spacing[1].x - (result_with_spacing[0].x + result_with_spacing[0].width);
+        assert!(gap_with_spacing >= gap_no_spacing);
+    }
+
+    #[test]
+    fn all_cells_within_bounds() {
+        let area = Rect::new(10, 10, 200, 150);
+        let result = auto_grid(area, 7, 1);
+
+        for (i, rect) in result.iter().enumerate() {
+            assert!(
+                rect.x >= area.x,
+                "Cell {} x position {} should be >= area.x {}",
+                i,
+                rect.x,
+                area.x
+            );
+            assert!(
+                rect.y >= area.y,
+                "Cell {} y position {} should be >= area.y {}",
+                i,
+                rect.y,
+                area.y
+            );
+            assert!(
+                rect.x + rect.width <= area.x + area.width,
+                "Cell {} right edge should be within area bounds",
+                i
+            );
+            assert!(
+                rect.y + rect.height <= area.y + area.height,
+                "Cell {} bottom edge should be withing area bounds",
+                i
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn debug_assertions_hold_on_valid_layouts() {
+        for spacing in [0, 1, 5, 50, 200] {
+            for n in 1..=30 {
+                auto_grid(Rect::new(3, 5, 97, 41), n, spacing);
+                auto_grid(Rect::new(0, 0, 2, 3), n, spacing);
+            }
+        }
+    }
+
+    #[test]
+    fn clamped_reports_dropped_items() {
+        let area = Rect::new(0, 0, 10, 6);
+        for n in 0..=20 {
+            let (cells, dropped) = auto_grid_clamped(area, n, 1, 3, 2);
+            assert_eq!(cells.len() + dropped, n);
+            for cell in cells {
+                assert!(cell.width >= 3, "{cell:?} is too narrow");
+                assert!(cell.height >= 2, "{cell:?} is too short");
+            }
+        }
+
+        let (cells, dropped) = auto_grid_clamped(area, 20, 1, 3, 2);
+        assert_eq!((cells.len(), dropped), (4, 16));
+    }
+
+    #[test]
+    fn clamped_keeps_everything_when_it_fits() {
+        let area = Rect::new(0, 0, 100, 100);
+        let (cells, dropped) = auto_grid_clamped(area, 9, 1, 10, 10);
+        assert_eq!(cells, auto_grid(area, 9, 1));
+        assert_eq!(dropped, 0);
+    }
+
+    #[test]
+    fn min_flex_cells_grow_past_the_minimum() {
+        let area = Rect::new(0, 0, 100, 60);
+        let cells = auto_grid_min_flex(area, 9, 2, 10, 5);
+
+        assert_eq!(cells.len(), 9);
+        assert_eq!(shape(&cells), (3, 3));
+        assert!(cells.iter().all(|cell| cell.width > 10 && cell.height > 5));
+        assert_eq!(cells[2].right(), area.right());
+        assert_eq!(cells[8].bottom(), area.bottom());
+        assert_invariants(area, 9, &cells);
+    }
+
+    #[test]
+    fn min_flex_reduces_tracks_that_dont_fit() {
+        // 3 columns of 20 don't fit in 50, and 5 rows of 5 don't fit in 23.
+        let area = Rect::new(0, 0, 50, 23);
+        let cells = auto_grid_min_flex(area, 9, 1, 20, 5);
+
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[0], Rect::new(0, 0, 25, 5));
+        assert_eq!(cells[1], Rect::new(26, 0, 24, 5));
+        assert_eq!(cells[7], Rect::new(26, 18, 24, 5));
+        assert!(cells[8..].iter().all(|&cell| cell == Rect::new(0, 0, 0, 0)));
+        assert_invariants(area, 9, &cells);
+
+        // A single track shrinks below its minimum.
+        let cells = auto_grid_min_flex(Rect::new(0, 0, 8, 4), 2, 0, 10, 10);
+        assert_eq!(cells, [Rect::new(0, 0, 8, 4), Rect::ZERO]);
+        assert_eq!(auto_grid_min_flex(Rect::ZERO, 3, 0, 1, 1), [Rect::ZERO; 3]);
+    }
+
+    #[test]
+    fn full_rows_hide_the_partial_row() {
+        let area = Rect::new(1, 2, 61, 47);
+        for (n, shown) in [
+            (0, 0),
+            (1, 1),
+            (2, 2),
+            (3, 2),
+            (5, 3),
+            (7, 6),
+            (9, 9),
+            (11, 8),
+        ] {
+            let (cells, hidden) = auto_grid_full_rows(area, n, 1);
+            assert_eq!((cells.len(), hidden), (shown, n - shown), "n {n}");
+            assert_eq!(cells, auto_grid(area, n, 1)[..shown]);
+        }
+    }
+
+    #[test]
+    fn square_dims() {
+        let area = Rect::new(0, 0, 90, 60);
+        let cells = auto_grid_square_dims(area, 5, 0);
+        assert_eq!(cells.len(), 5);
+        assert_eq!(shape(&cells), (2, 3));
+        assert!(
+            cells
+                .iter()
+                .all(|cell| (cell.width, cell.height) == (30, 20))
+        );
+
+        // The slots of the perfect square, filled or not.
+        for (n, square) in [(1, 1), (2, 4), (4, 4), (7, 9), (10, 16), (30, 36)] {
+            let cells = auto_grid_square_dims(area, n, 1);
+            assert_eq!(cells, auto_grid(area, square, 1)[..n], "n {n}");
+        }
+    }
+
+    #[test]
+    fn overflow_sizes_visible_cells_only() {
+        let area = Rect::new(0, 0, 100, 100);
+        let (cells, overflow) = auto_grid_overflow(area, 10, 0, 4);
+
+        assert_eq!(cells.len(), 4);
+        assert_eq!(overflow, 6);
+        assert_eq!(cells, auto_grid(area, 4, 0));
+
+        let (cells, overflow) = auto_grid_overflow(area, 3, 0, 4);
+        assert_eq!(cells.len(), 3);
+        assert_eq!(overflow, 0);
+    }
+
+    /// Counts the distinct rows and columns of `cells`.
+    fn shape(cells: &[Rect]) -> (usize, usize) {
+        let mut ys: Vec<u16> = cells.iter().map(|cell| cell.y).collect();
+        let mut xs: Vec<u16> = cells.iter().map(|cell| cell.x).collect();
+        ys.sort_unstable();
+        ys.dedup();
+        xs.sort_unstable();
+        xs.dedup();
+        (ys.len(), xs.len())
+    }
+
+    #[test]
+    fn oriented_grids() {
+        let area = Rect::new(0, 0, 120, 120);
+        for n in 1..=30 {
+            let auto = auto_grid_oriented(area, n, 0, Orientation::Auto);
+            assert_eq!(auto, auto_grid(area, n, 0));
+
+            let (rows, cols) = shape(&auto_grid_oriented(area, n, 0, Orientation::Landscape));
+            assert!(
+                rows <= cols,
+                "landscape n={n} has {rows} rows and {cols} cols"
+            );
+
+            let portrait = auto_grid_oriented(area, n, 0, Orientation::Portrait);
+            assert_eq!(portrait.len(), n);
+            let (rows, cols) = shape(&portrait);
+            assert!(
+                cols <= rows,
+                "portrait n={n} has {rows} rows and {cols} cols"
+            );
+        }
+
+        let (rows, cols) = shape(&auto_grid_oriented(area, 6, 0, Orientation::Portrait));
+        assert_eq!((rows, cols), (3, 2));
+    }
+
+    #[test]
+    fn target_width_columns() {
+        let area = Rect::new(0, 0, 120, 40);
+        let result = auto_grid_target_width(area, 8, 0, 30);
+        assert_eq!(shape(&result), (2, 4));
+        assert!(result.iter().all(|cell| cell.width == 30));
+
+        let result = auto_grid_target_width(area, 3, 0, 200);
+        assert_eq!(shape(&result), (3, 1));
+    }
+
+    #[test]
+    fn target_width_accounts_for_spacing() {
+        let area = Rect::new(0, 0, 120, 40);
+        // 4 columns of 30 don't fit with the spacing, 3 columns of 38 do.
+        let result = auto_grid_target_width(area, 6, 2, 30);
+        assert_eq!(shape(&result), (2, 3));
+        assert!(result.iter().all(|cell| cell.width >= 30));
+
+        // 4 columns of 30 fit exactly with 3 gaps of 2.
+        let area = Rect::new(0, 0, 126, 40);
+        let result = auto_grid_target_width(area, 6, 2, 30);
+        assert_eq!(shape(&result), (2, 4));
+        assert!(result.iter().all(|cell| cell.width == 30));
+    }
+
+    #[test]
+    fn target_width_rows_saturate() {
+        let area = Rect::new(0, 0, 10, 100);
+        for n in [65_535, 65_536] {
+            let result = auto_grid_target_width(area, n, 0, 10);
+            assert_eq!(result.len(), n);
+            assert_eq!(result.iter().filter(|cell| !cell.is_empty()).count(), 100);
+        }
+    }
+
+    #[test]
+    fn even_gutters() {
+        let area = Rect::new(5, 5, 100, 50);
+        let spacing = 3;
+        let result = auto_grid_even_gutters(area, 4, spacing);
+
+        assert_eq!(result[0].x - area.x, spacing);
+        assert_eq!(result[0].y - area.y, spacing);
+        assert_eq!(result[1].x - result[0].right(), spacing);
+        assert_eq!(result[2].y - result[0].bottom(), spacing);
+        assert_eq!(area.right() - result[3].right(), spacing);
+        assert_eq!(area.bottom() - result[3].bottom(), spacing);
+    }
+
+    #[test]
+    fn even_gutters_too_large() {
+        let area = Rect::new(0, 0, 10, 10);
+        assert!(auto_grid_even_gutters(area, 4, 5).is_empty());
+        assert_eq!(auto_grid_even_gutters(area, 1, 4).len(), 1);
+    }
+
+    #[test]
+    fn margins_inset_each_side() {
+        let area = Rect::new(5, 5, 100, 50);
+        let cells = auto_grid_margins(area, 4, 1, 3, 0, 0, 0);
+        assert_eq!(cells[0].y, area.y + 3);
+        assert_eq!(cells[0].x, area.x);
+        assert_eq!(cells[3].right(), area.right());
+        assert_eq!(cells[3].bottom(), area.bottom());
+
+        let cells = auto_grid_margins(area, 4, 1, 1, 2, 3, 4);
+        assert_eq!(cells, auto_grid(Rect::new(9, 6, 94, 46), 4, 1));
+    }
+
+    #[test]
+    fn margins_too_large() {
+        let area = Rect::new(0, 0, 10, 10);
+        assert!(auto_grid_margins(area, 4, 0, 0, 6, 0, 4).is_empty());
+        assert!(auto_grid_margins(area, 4, 0, u16::MAX, 0, u16::MAX, 0).is_empty());
+        assert!(auto_grid_margins(area, 1, 0, 10, 0, 0, 0).is_empty());
+        assert_eq!(
+            auto_grid_margins(area, 1, 0, 9, 0, 0, 9),
+            [Rect::new(9, 9, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn footer_below_the_grid() {
+        let area = Rect::new(2, 3, 30, 21);
+        let (cells, footer) = auto_grid_with_footer(area, 5, 2, 4);
+
+        assert_eq!(footer, Rect::new(2, 20, 30, 4));
+        assert_eq!(cells, auto_grid(Rect::new(2, 3, 30, 15), 5, 2));
+        assert_eq!(footer.y - cells[4].bottom(), 2);
+
+        let (cells, footer) = auto_grid_with_footer(area, 5, 2, 0);
+        assert_eq!(footer, Rect::new(2, 24, 30, 0));
+        assert_eq!(cells, auto_grid(area, 5, 2));
+    }
+
+    #[test]
+    fn footer_saturates() {
+        let area = Rect::new(2, 3, 30, 21);
+        for footer_height in [20, 21, 100] {
+            let (cells, footer) = auto_grid_with_footer(area, 3, 2, footer_height);
+            assert_eq!(cells, [Rect::new(2, 3, 0, 0); 3]);
+            assert_eq!(footer.bottom(), area.bottom());
+            assert_eq!(footer.height, footer_height.min(21));
+        }
+    }
+
+    #[test]
+    fn fraction_grid_takes_the_left_part() {
+        let area = Rect::new(2, 3, 50, 20);
+        let (cells, leftover) = auto_grid_in_fraction(area, 5, 1, 70, 100);
+
+        assert_eq!(cells, auto_grid(Rect::new(2, 3, 35, 20), 5, 1));
+        assert_eq!(leftover, Rect::new(37, 3, 15, 20));
+        let grid_width = cells.iter().map(|cell| cell.right()).max().unwrap() - area.x;
+        assert_eq!(grid_width, 35);
+
+        let (cells, leftover) = auto_grid_in_fraction(area, 2, 0, 200, 50);
+        assert_eq!(cells, auto_grid(Rect::new(2, 3, 50, 10), 2, 0));
+        assert_eq!(leftover, Rect::new(52, 3, 0, 20));
+    }
+
+    #[test]
+    fn rows_spec_mixes_fixed_and_grid_rows() {
+        let area = Rect::new(2, 3, 30, 21);
+        let rows = auto_grid_rows_spec(area, 1, &[RowSpec::Fixed(3), RowSpec::Grid(4)]);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], [Rect::new(2, 3, 30, 3)]);
+        assert_eq!(rows[1], auto_grid(Rect::new(2, 7, 30, 17), 4, 1));
+        assert!(auto_grid_rows_spec(area, 1, &[]).is_empty());
+    }
+
+    #[test]
+    fn snapped_positions_are_multiples_of_the_step() {
+        for area in [
+            Rect::new(0, 0, 100, 60),
+            Rect::new(3, 5, 97, 41),
+            Rect::new(1, 1, 13, 9),
+        ] {
+            for step in [2, 3, 4, 8] {
+                for n in [1, 2, 5, 9, 17, 40] {
+                    for spacing in [0, 1, 3] {
+                        let cells = auto_grid_snapped(area, n, spacing, step);
+                        assert_invariants(area, n, &cells);
+                        for cell in cells.iter().filter(|cell| !cell.is_empty()) {
+                            assert_eq!(cell.x % step, 0, "{area:?} {n} {step}: {cell:?}");
+                            assert_eq!(cell.y % step, 0, "{area:?} {n} {step}: {cell:?}");
+                        }
+                        for pair in cells.windows(2) {
+                            if pair[0].y == pair[1].y && !pair[1].is_empty() {
+                                assert!(pair[1].x - pair[0].right() >= spacing);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn snapping_by_one_changes_nothing() {
+        let area = Rect::new(3, 5, 97, 41);
+        for step in [0, 1] {
+            assert_eq!(auto_grid_snapped(area, 7, 2, step), auto_grid(area, 7, 2));
+        }
+    }
 
-    let col_constraints: Vec<Constraint> =
-        std::iter::repeat_n(Constraint::Ratio(1, cols.into()), cols as usize).collect();
+    #[test]
+    fn enumerate_coordinates() {
+        let area = Rect::new(1, 2, 60, 40);
+        let cells = auto_grid_enumerate(area, 6, 1);
 
-    let row_areas = Layout::vertical(row_constraints)
-        .spacing(spacing)
-        .split(area);
+        let coordinates: Vec<_> = cells.iter().map(|&(row, col, _)| (row, col)).collect();
+        assert_eq!(
+            coordinates,
+            [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        let rects: Vec<_> = cells.iter().map(|&(_, _, cell)| cell).collect();
+        assert_eq!(rects, auto_grid(area, 6, 1));
+        assert!(auto_grid_enumerate(area, 0, 1).is_empty());
+    }
 
-    let mut out = Vec::with_capacity(n);
-    'outer: for r in 0..rows as usize {
-        let col_areas = Layout::horizontal(col_constraints.clone())
-            .spacing(spacing)
-            .split(row_areas[r]);
-        for &rect in col_areas.iter() {
-            if out.len() == n {
-                break 'outer;
-            }
-            out.push(rect);
+    #[test]
+    fn enumerate_coordinates_near_u16_boundaries() {
+        let area = Rect::new(0, 0, 10, 10);
+        for n in [65_535, 65_536, 65_537] {
+            let (rows, cols) = dimensions(n);
+            let cells = auto_grid_enumerate(area, n, 0);
+            assert_eq!(cells.len(), n);
+            let &(row, col, _) = cells.last().unwrap();
+            assert_eq!(usize::from(row), (n - 1) / usize::from(cols), "n={n}");
+            assert_eq!(usize::from(col), (n - 1) % usize::from(cols), "n={n}");
+            assert!(row < rows);
         }
     }
-    out
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
-    fn empty_grid() {
-        let area = Rect::new(0, 0, 100, 100);
-        let result = auto_grid(area, 0, 0);
-        assert_eq!(result.len(), 0);
+    fn grouped_rows_flatten_to_auto_grid() {
+        let area = Rect::new(1, 2, 60, 40);
+        let rows = auto_grid_grouped(area, 7, 1);
+
+        let lengths: Vec<_> = rows.iter().map(Vec::len).collect();
+        assert_eq!(lengths, [3, 3, 1]);
+        assert!(
+            rows.iter()
+                .all(|row| row.iter().all(|cell| cell.y == row[0].y))
+        );
+        assert_eq!(rows.concat(), auto_grid(area, 7, 1));
+        assert!(auto_grid_grouped(area, 0, 1).is_empty());
     }
 
     #[test]
-    fn single_cell() {
-        let area = Rect::new(0, 0, 100, 100);
-        let result = auto_grid(area, 1, 0);
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0], area, "Single cell should fill entire area");
+    fn bounds_enclose_the_cells() {
+        let area = Rect::new(3, 4, 91, 53);
+        let (cells, bounds) = auto_grid_bounds(area, 9, 2);
+        assert_eq!(
+            (bounds.right(), bounds.bottom()),
+            (cells[8].right(), cells[8].bottom())
+        );
+        assert_eq!(bounds, area);
+
+        let (cells, bounds) = auto_grid_bounds(area, 5, 2);
+        assert_eq!(bounds.right(), cells[2].right());
+        assert_eq!(bounds.bottom(), cells[4].bottom());
+
+        let area = Rect::new(3, 4, 7, 4);
+        let (cells, bounds) = auto_grid_bounds(area, 16, 1);
+        // Room for the 4 columns but only 1 of the 4 rows.
+        assert_eq!(bounds, Rect::new(3, 4, 7, 1));
+        assert_eq!(
+            (bounds.right(), bounds.bottom()),
+            (cells[3].right(), cells[3].bottom())
+        );
+        assert!(cells[4..].iter().all(|cell| cell.is_empty()));
+
+        assert_eq!(auto_grid_bounds(area, 0, 1).1, Rect::new(3, 4, 0, 0));
+        assert_eq!(auto_grid_bounds(area, 4, 10).1, Rect::new(3, 4, 0, 0));
     }
 
     #[test]
-    fn four_cells_perfect_square() {
-        let area = Rect::new(0, 0, 100, 100);
-        let result = auto_grid(area, 4, 0);
+    fn overlapping_cells() {
+        let area = Rect::new(3, 4, 90, 60);
+        let flat = auto_grid(area, 9, 0);
+        let result = auto_grid_overlap(area, 9, 2);
 
-        assert_eq!(result.len(), 4);
+        assert!(result[1].x < result[0].x + result[0].width);
+        assert_eq!(result[0].right() - result[1].x, 2);
+        assert_eq!(result[0].bottom() - result[3].y, 2);
+        for (cell, flat) in result.iter().zip(&flat) {
+            assert_eq!(cell.as_position(), flat.as_position());
+            assert!(contains(area, *cell));
+        }
+        assert_eq!(result[8], flat[8]);
+        assert_eq!(
+            (result[2].right(), result[6].bottom()),
+            (area.right(), area.bottom())
+        );
 
-        // Top left
-        assert_eq!(result[0].x, 0);
-        assert_eq!(result[0].y, 0);
-        assert_eq!(result[0].width, 50);
-        assert_eq!(result[0].height, 50);
+        assert_eq!(auto_grid_overlap(area, 9, 0), flat);
+        let huge = auto_grid_overlap(area, 4, u16::MAX);
+        assert_eq!(huge[0], area);
+    }
 
-        // Top right
-        assert_eq!(result[1].x, 50);
-        assert_eq!(result[1].y, 0);
+    #[test]
+    fn full_grid_returns_empty_slots() {
+        let area = Rect::new(0, 0, 90, 60);
+        let (populated, empty) = auto_grid_full(area, 5, 1);
 
-        // Bottom left
-        assert_eq!(result[2].x, 0);
-        assert_eq!(result[2].y, 50);
+        assert_eq!(populated, auto_grid(area, 5, 1));
+        assert_eq!(empty.len(), 1);
+        assert_eq!(empty[0].y, populated[3].y);
+        assert_eq!(empty[0].x, populated[2].x);
 
-        // Bottom right
-        assert_eq!(result[3].x, 50);
-        assert_eq!(result[3].y, 50);
+        let (populated, empty) = auto_grid_full(area, 9, 1);
+        assert_eq!(populated.len(), 9);
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn nine_cells() {
-        let area = Rect::new(0, 0, 99, 99);
-        let result = auto_grid(area, 9, 0);
+    fn split_constraints_matches_per_row_layout() {
+        for (area, n, spacing) in [
+            (Rect::new(0, 0, 100, 100), 7, 1),
+            (Rect::new(3, 9, 77, 41), 23, 2),
+            (Rect::new(0, 0, 13, 300), 100, 0),
+        ] {
+            let (rows, cols) = dimensions(n);
+            let row_areas = Layout::vertical(vec![Constraint::Ratio(1, rows.into()); rows.into()])
+                .spacing(spacing)
+                .split(area);
+            let expected: Vec<Rect> = row_areas
+                .iter()
+                .flat_map(|&row_area| {
+                    Layout::horizontal(vec![Constraint::Ratio(1, cols.into()); cols.into()])
+                        .spacing(spacing)
+                        .split(row_area)
+                        .to_vec()
+                })
+                .take(n)
+                .collect();
 
-        assert_eq!(result.len(), 9);
+            let rows = vec![Constraint::Ratio(1, rows.into()); rows.into()];
+            let cols = vec![Constraint::Ratio(1, cols.into()); cols.into()];
+            let mut result = split_constraints(area, &rows, &cols, spacing, spacing);
+            result.truncate(n);
+            assert_eq!(result, expected);
+        }
+    }
 
-        // first cell
-        assert_eq!(result[0].x, 0);
-        assert_eq!(result[0].y, 0);
-        assert_eq!(result[0].width, 33);
-        assert_eq!(result[0].height, 33);
+    #[test]
+    fn rows_first_heuristic() {
+        let area = Rect::new(0, 0, 120, 120);
 
-        // last cell
-        assert_eq!(result[8].x, 66);
-        assert_eq!(result[8].y, 66);
+        let columns_first = auto_grid_with_hint(area, 6, 0, DimensionHint::ColumnsFirst);
+        let rows_first = auto_grid_with_hint(area, 6, 0, DimensionHint::RowsFirst);
+        assert_eq!(columns_first, auto_grid(area, 6, 0));
+        assert_eq!(shape(&columns_first), (2, 3));
+        assert_eq!(shape(&rows_first), (3, 2));
+
+        assert_eq!(
+            grid_shape(8, DimensionHint::ColumnsFirst),
+            grid_shape(8, DimensionHint::RowsFirst)
+        );
+        assert_eq!(grid_shape(0, DimensionHint::RowsFirst), (0, 0));
     }
 
     #[test]
-    fn non_square_grid() {
-        let area = Rect::new(0, 0, 100, 100);
-        let result = auto_grid(area, 6, 0);
+    fn hit_test_cells() {
+        let area = Rect::new(0, 0, 91, 91);
+        let cells = auto_grid(area, 9, 2);
 
-        assert_eq!(result.len(), 6);
+        assert_eq!(hit_test(&cells, cells[2].x, cells[2].y), Some(2));
+        assert_eq!(
+            hit_test(&cells, cells[2].right() - 1, cells[2].bottom() - 1),
+            Some(2)
+        );
+        // In the gutter right after the first cell.
+        assert_eq!(hit_test(&cells, cells[0].right(), cells[0].y), None);
+        assert_eq!(hit_test(&cells, area.right(), 0), None);
+        assert_eq!(hit_test(&[], 0, 0), None);
+    }
 
-        // First row
-        assert_eq!(result[0].y, result[1].y);
-        assert_eq!(result[1].y, result[2].y);
+    #[test]
+    fn nearest_hit_snaps_across_the_spacing() {
+        // Columns at 2..7 and 10..15, rows at 1..4 and 7..10.
+        let area = Rect::new(2, 1, 13, 9);
+        let cells = auto_grid(area, 4, 3);
+        let col = |x: u16| match x {
+            0..=8 => Some(0),
+            9..=16 => Some(1),
+            _ => None,
+        };
+        let row = |y: u16| match y {
+            0..=5 => Some(0),
+            6..=11 => Some(1),
+            _ => None,
+        };
 
-        // Second row
-        assert_eq!(result[3].y, result[4].y);
-        assert_eq!(result[4].y, result[5].y);
+        for x in 0..20 {
+            for y in 0..15 {
+                let expected = col(x).zip(row(y)).map(|(col, row)| row * 2 + col);
+                assert_eq!(hit_test_nearest(&cells, x, y, 2), expected, "({x}, {y})");
+                assert_eq!(
+                    hit_test_nearest(&cells, x, y, 0),
+                    hit_test(&cells, x, y),
+                    "({x}, {y})"
+                );
+            }
+        }
 
-        assert_ne!(result[0].y, result[3].y);
+        // The middle of the spacing is 2 away from both sides.
+        assert_eq!(hit_test_nearest(&cells, 8, 2, 1), None);
+        assert_eq!(hit_test_nearest(&cells, 7, 2, 1), Some(0));
+        assert_eq!(hit_test_nearest(&[Rect::new(0, 0, 0, 5)], 0, 0, 3), None);
     }
 
     #[test]
-    fn with_spacing() {
-        let area = Rect::new(0, 0, 100, 100);
-        let result_no_spacing = auto_grid(area, 4, 0);
-        let result_with_spacing = auto_grid(area, 4, 2);
+    fn row_bands_span_the_grid() {
+        let area = Rect::new(2, 3, 62, 31);
+        let cells = auto_grid(area, 7, 1);
+        let bands = auto_grid_rows(area, 7, 1);
 
-        assert_eq!(result_no_spacing.len(), 4);
-        assert_eq!(result_with_spacing.len(), 4);
+        assert_eq!(bands.len(), 3);
+        for (row, band) in bands.iter().enumerate() {
+            assert_eq!((band.x, band.right()), (area.x, area.right()));
+            let first = cells[row * 3];
+            assert_eq!((band.y, band.height), (first.y, first.height));
+        }
+        // Only the first column and the first two rows get room.
+        let bands = auto_grid_rows(Rect::new(0, 0, 5, 6), 9, 2);
+        assert_eq!(
+            bands,
+            [Rect::new(0, 0, 1, 1), Rect::new(0, 3, 1, 1), Rect::ZERO]
+        );
+        assert!(auto_grid_rows(area, 0, 1).is_empty());
+    }
 
-        assert!(result_with_spacing[0].width <= result_no_spacing[0].width);
-        assert!(result_with_spacing[0].height <= result_no_spacing[0].height);
+    #[test]
+    fn column_bands_span_the_grid() {
+        let area = Rect::new(2, 3, 62, 31);
+        let cells = auto_grid(area, 7, 1);
+        let columns = auto_grid_columns(area, 7, 1);
 
-        let gap_no_spacing =
-            result_no_spacing[1].x - (result_no_spacing[0].x + result_no_spacing[0].width);
-        let gap_with_spacing =
-            result_with_spacing[1].x - (result_with_spacing[0].x + result_with_spacing[0].width);
-        assert!(gap_with_spacing >= gap_no_spacing);
+        assert_eq!(columns.len(), 3);
+        for column in &columns {
+            assert_eq!((column.y, column.bottom()), (area.y, area.bottom()));
+        }
+        // Every row band crosses every column band on the cell of that row and column, even on
+        // the empty slots of the last row.
+        let full = auto_grid(area, 9, 1);
+        assert_eq!(cells, full[..7]);
+        for (row, band) in auto_grid_rows(area, 7, 1).iter().enumerate() {
+            for (col, column) in columns.iter().enumerate() {
+                assert_eq!(band.intersection(*column), full[row * 3 + col]);
+            }
+        }
+
+        let columns = auto_grid_columns(Rect::new(0, 0, 6, 5), 9, 2);
+        assert_eq!(
+            columns,
+            [Rect::new(0, 0, 1, 1), Rect::new(3, 0, 1, 1), Rect::ZERO]
+        );
+        assert!(auto_grid_columns(area, 0, 1).is_empty());
     }
 
     #[test]
-    fn all_cells_within_bounds() {
-        let area = Rect::new(10, 10, 200, 150);
-        let result = auto_grid(area, 7, 1);
+    fn rounding_variants() {
+        assert_eq!(rounded_dimensions(7, Rounding::Ceil), (3, 3));
+        assert_eq!(rounded_dimensions(7, Rounding::Floor), (4, 2));
+        assert_eq!(rounded_dimensions(7, Rounding::Nearest), (3, 3));
+        assert_eq!(rounded_dimensions(5, Rounding::Nearest), (3, 2));
+        assert_eq!(rounded_dimensions(1, Rounding::Floor), (1, 1));
 
-        for (i, rect) in result.iter().enumerate() {
-            assert!(
-                rect.x >= area.x,
-                "Cell {} x position {} should be >= area.x {}",
-                i,
-                rect.x,
-                area.x
-            );
-            assert!(
-                rect.y >= area.y,
-                "Cell {} y position {} should be >= area.y {}",
-                i,
-                rect.y,
-                area.y
+        let area = Rect::new(0, 0, 120, 120);
+        for n in 1..=100 {
+            for rounding in [Rounding::Ceil, Rounding::Floor, Rounding::Nearest] {
+                let (rows, cols) = rounded_dimensions(n, rounding);
+                assert!(
+                    usize::from(rows) * usize::from(cols) >= n,
+                    "{n} {rounding:?}"
+                );
+                assert!(
+                    usize::from(rows - 1) * usize::from(cols) < n,
+                    "{n} {rounding:?}"
+                );
+            }
+            assert_eq!(rounded_dimensions(n, Rounding::Ceil), dimensions(n));
+            assert_eq!(
+                auto_grid_rounded(area, n, 1, Rounding::Ceil),
+                auto_grid(area, n, 1)
             );
+        }
+
+        let floor = auto_grid_rounded(area, 7, 0, Rounding::Floor);
+        assert_eq!(shape(&floor), (4, 2));
+        let nearest = auto_grid_rounded(area, 5, 0, Rounding::Nearest);
+        assert_eq!(shape(&nearest), (3, 2));
+    }
+
+    #[test]
+    fn minimize_waste_heuristic() {
+        let hint = DimensionHint::MinimizeWaste;
+        assert_eq!(grid_shape(5, hint), (2, 3));
+        assert_eq!(grid_shape(7, hint), (2, 4));
+        assert_eq!(grid_shape(8, hint), (2, 4));
+        assert_eq!(grid_shape(9, hint), (3, 3));
+        assert_eq!(grid_shape(0, hint), (0, 0));
+
+        for n in 1..=500 {
+            let (rows, cols) = grid_shape(n, hint);
+            let (default_rows, default_cols) = dimensions(n);
+            let slots = usize::from(rows) * usize::from(cols);
             assert!(
-                rect.x + rect.width <= area.x + area.width,
-                "Cell {} right edge should be within area bounds",
-                i
+                slots >= n && slots - usize::from(cols) < n,
+                "{n}: {rows}x{cols}"
             );
+            assert!(slots <= usize::from(default_rows) * usize::from(default_cols));
+        }
+
+        let area = Rect::new(0, 0, 80, 40);
+        assert_eq!(shape(&auto_grid_with_hint(area, 7, 0, hint)), (2, 4));
+        assert!(grid_shape(MAX_CELLS, hint).0 > 0);
+    }
+
+    #[test]
+    fn integer_roots_match_float_roots() {
+        let boundaries = (1..=u16::MAX as usize).step_by(251).flat_map(|root| {
+            let square = root * root;
+            [
+                square - 1,
+                square,
+                square + 1,
+                square + root,
+                square + root + 1,
+            ]
+        });
+        for n in (0..=10_000)
+            .chain(boundaries)
+            .chain([MAX_CELLS - 1, MAX_CELLS])
+        {
+            let root = (n as f64).sqrt();
+            let cols = root.ceil() as u16;
+            let rows = if n == 0 {
+                0
+            } else {
+                n.div_ceil(usize::from(cols)) as u16
+            };
+            assert_eq!(dimensions(n), (rows, cols), "n {n}");
+
+            for (rounding, cols) in [
+                (Rounding::Ceil, root.ceil()),
+                (Rounding::Floor, root.floor()),
+                (Rounding::Nearest, root.round()),
+            ] {
+                let cols = (cols as usize).max(1);
+                assert_eq!(rounded_dimensions(n, rounding).1, cols as u16, "n {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn dimensions_near_u16_boundaries() {
+        for n in [
+            65_535,
+            65_536,
+            65_537,
+            MAX_CELLS - 1,
+            MAX_CELLS,
+            MAX_CELLS + 1,
+            usize::MAX,
+        ] {
+            let (rows, cols) = dimensions(n);
+            let (rows, cols) = (usize::from(rows), usize::from(cols));
+            let n = n.min(MAX_CELLS);
+            assert!(rows * cols >= n, "{n}: {rows}x{cols}");
+            assert!((rows - 1) * cols < n, "{n}: {rows}x{cols}");
+        }
+
+        assert_eq!(dimensions(65_536), (256, 256));
+        assert_eq!(dimensions(65_537), (256, 257));
+        assert_eq!(dimensions(usize::MAX), (u16::MAX, u16::MAX));
+    }
+
+    #[test]
+    fn large_cells_are_not_clipped() {
+        // Larger than `u16::MAX` cells, which `Rect::new` shrinks in ratatui 0.28.
+        let area = new_rect(0, 0, 1000, 1000);
+        assert_eq!(auto_grid(area, 1, 0), [area]);
+        assert_eq!(auto_grid(area, 4, 0)[3], new_rect(500, 500, 500, 500));
+    }
+
+    #[test]
+    fn large_n_keeps_a_consistent_shape() {
+        let area = new_rect(0, 0, 1000, 1000);
+        for n in [65_536, 65_537] {
+            let result = auto_grid(area, n, 0);
+            let (rows, cols) = grid_shape(n, DimensionHint::ColumnsFirst);
+            assert_eq!(result.len(), n);
+            assert_eq!(shape(&result), (rows.into(), cols.into()));
+        }
+    }
+
+    #[test]
+    fn separators_past_max_cells() {
+        let area = Rect::new(0, 0, 100, 100);
+        let expected = auto_grid_separators(area, MAX_CELLS, 1);
+        assert_eq!(auto_grid_separators(area, MAX_CELLS + 1, 1), expected);
+        assert_eq!(auto_grid_separators(area, usize::MAX, 1), expected);
+    }
+
+    #[test]
+    fn separators_of_a_full_grid() {
+        let area = Rect::new(2, 3, 100, 50);
+        let (vertical, horizontal) = auto_grid_separators(area, 12, 3);
+        let cells = auto_grid(area, 12, 3);
+
+        assert_eq!(vertical.len(), 4 - 1);
+        assert_eq!(horizontal.len(), 3 - 1);
+        for (col, separator) in vertical.iter().enumerate() {
+            assert_eq!(separator.width, 1);
+            assert!(separator.x > cells[col].right() && separator.x < cells[col + 1].x);
+            assert_eq!((separator.y, separator.bottom()), (area.y, area.bottom()));
+        }
+        for (row, separator) in horizontal.iter().enumerate() {
+            assert_eq!(separator.height, 1);
+            assert_eq!(separator.y, cells[row * 4].bottom() + 1);
+            assert_eq!((separator.x, separator.right()), (area.x, area.right()));
+        }
+        for cell in cells {
             assert!(
-                rect.y + rect.height <= area.y + area.height,
-                "Cell {} bottom edge should be withing area bounds",
-                i
+                vertical
+                    .iter()
+                    .chain(&horizontal)
+                    .all(|s| !s.intersects(cell))
             );
         }
     }
 
+    #[test]
+    fn separators_of_a_partial_grid() {
+        let area = Rect::new(0, 0, 100, 30);
+        let cells = auto_grid(area, 10, 1);
+        let (vertical, horizontal) = auto_grid_separators(area, 10, 1);
+
+        // 4 columns, with 2 cells in the last row.
+        assert_eq!(vertical.len(), 3);
+        assert_eq!(vertical[0].bottom(), cells[9].bottom());
+        assert_eq!(vertical[1].bottom(), cells[7].bottom());
+        assert_eq!(vertical[2].bottom(), cells[7].bottom());
+        assert_eq!(horizontal.len(), 2);
+
+        assert_eq!(auto_grid_separators(area, 10, 0), (Vec::new(), Vec::new()));
+        assert_eq!(auto_grid_separators(area, 1, 1), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn quadrants_match_four_cells() {
+        for (area, spacing) in [
+            (Rect::new(0, 0, 100, 100), 0),
+            (Rect::new(3, 5, 81, 17), 1),
+            (Rect::new(0, 0, 5, 5), 10),
+            (Rect::new(2, 2, 0, 10), 0),
+        ] {
+            let grid = auto_grid(area, 4, spacing);
+            for (quadrant, cell) in quadrants(area, spacing).iter().zip(&grid) {
+                assert_eq!(quadrant, cell);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_size_areas() {
+        let area = Rect::new(2, 3, 41, 21);
+        let [a, b, c, d] = auto_grid_areas::<4>(area, 1);
+        assert_eq!([a, b, c, d][..], auto_grid(area, 4, 1)[..]);
+        assert_eq!((a.right() + 1, a.bottom() + 1), (b.x, c.y));
+        assert_eq!(d.right(), area.right());
+
+        let [] = auto_grid_areas::<0>(area, 1);
+        let [only] = auto_grid_areas(area, 1);
+        assert_eq!(only, area);
+    }
+
+    #[test]
+    fn single_row_of_columns() {
+        let area = Rect::new(4, 2, 100, 20);
+        let result = columns(area, 3, 0);
+        assert_invariants(area, 3, &result);
+
+        assert!(
+            result
+                .iter()
+                .all(|cell| cell.y == area.y && cell.height == area.height)
+        );
+        assert!(result.windows(2).all(|pair| pair[0].right() == pair[1].x));
+        assert_eq!(result[2].right(), area.right());
+    }
+
+    #[test]
+    fn single_column_of_rows() {
+        let area = Rect::new(4, 2, 20, 100);
+        let result = rows(area, 4, 1);
+        assert_invariants(area, 4, &result);
+
+        assert!(
+            result
+                .iter()
+                .all(|cell| cell.x == area.x && cell.width == area.width)
+        );
+        assert!(
+            result
+                .windows(2)
+                .all(|pair| pair[0].bottom() + 1 == pair[1].y)
+        );
+        assert_eq!(result[3].bottom(), area.bottom());
+    }
+
+    #[test]
+    fn exact_widths_are_pinned() {
+        let widths = |area: Rect, n: usize, spacing: u16| -> Vec<u16> {
+            let cols = usize::from(dimensions(n).1);
+            let cells = auto_grid_exact(area, n, spacing);
+            assert_eq!(cells, auto_grid(area, n, spacing));
+            cells.iter().take(cols).map(|cell| cell.width).collect()
+        };
+
+        assert_eq!(widths(Rect::new(0, 0, 100, 10), 3, 0), [50, 50]);
+        assert_eq!(widths(Rect::new(0, 0, 100, 10), 9, 0), [34, 33, 33]);
+        assert_eq!(widths(Rect::new(0, 0, 100, 10), 9, 1), [33, 33, 32]);
+        assert_eq!(widths(Rect::new(7, 0, 81, 10), 16, 2), [19, 19, 19, 18]);
+        assert_eq!(widths(Rect::new(0, 0, 10, 10), 25, 0), [2, 2, 2, 2, 2]);
+        assert_eq!(
+            widths(Rect::new(0, 0, 13, 10), 49, 1),
+            [1, 1, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(
+            widths(Rect::new(0, 0, 19, 10), 49, 1),
+            [2, 2, 2, 2, 2, 2, 1]
+        );
+
+        let heights: Vec<u16> = auto_grid_exact(Rect::new(0, 3, 10, 23), 12, 1)
+            .iter()
+            .step_by(4)
+            .map(|cell| cell.height)
+            .collect();
+        assert_eq!(heights, [7, 7, 7]);
+        let heights: Vec<u16> = auto_grid_exact(Rect::new(0, 3, 10, 24), 12, 1)
+            .iter()
+            .step_by(4)
+            .map(|cell| cell.height)
+            .collect();
+        assert_eq!(heights, [8, 7, 7]);
+    }
+
+    #[test]
+    fn fair_rounding_sweep() {
+        for length in 10..=200 {
+            for tracks in 1..=12 {
+                for spacing in [0, 1] {
+                    let area = Rect::new(0, 0, length, length);
+                    let row = split_grid(area, 1, tracks, spacing, spacing);
+                    let column = split_grid(area, tracks, 1, spacing, spacing);
+
+                    let widths: Vec<u16> = row.iter().map(|cell| cell.width).collect();
+                    let heights: Vec<u16> = column.iter().map(|cell| cell.height).collect();
+                    for sizes in [widths, heights] {
+                        let (min, max) = (sizes.iter().min(), sizes.iter().max());
+                        assert!(
+                            max.unwrap() - min.unwrap() <= 1,
+                            "{length}/{tracks}: {sizes:?}"
+                        );
+                        // The larger tracks come first.
+                        assert!(
+                            sizes.is_sorted_by(|a, b| a >= b),
+                            "{length}/{tracks}: {sizes:?}"
+                        );
+                    }
+                    assert_eq!(row.last().unwrap().right(), area.right());
+                    assert_eq!(column.last().unwrap().bottom(), area.bottom());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_plus_offset_matches_auto_grid() {
+        for (x, y, width, height) in [(10, 5, 100, 100), (3, 71, 77, 41), (1000, 0, 13, 9)] {
+            let area = Rect::new(x, y, width, height);
+            for n in 1..=20 {
+                let translated: Vec<Rect> = auto_grid_normalized(width, height, n, 1)
+                    .into_iter()
+                    .map(|cell| Rect {
+                        x: cell.x + x,
+                        y: cell.y + y,
+                        ..cell
+                    })
+                    .collect();
+                assert_eq!(translated, auto_grid(area, n, 1), "{area:?}, n = {n}");
+            }
+        }
+    }
+
     #[test]
     fn row_major_order() {
         let area = Rect::new(0, 0, 100, 100);
@@ -220,4 +3079,199 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn spacing_pct_scales_with_area() {
+        let small = auto_grid_spacing_pct(Rect::new(0, 0, 100, 100), 4, 10, 10);
+        let large = auto_grid_spacing_pct(Rect::new(0, 0, 200, 200), 4, 10, 10);
+
+        let small_gap = small[1].x - (small[0].x + small[0].width);
+        let large_gap = large[1].x - (large[0].x + large[0].width);
+        assert_eq!(small_gap, 10);
+        assert_eq!(large_gap, 2 * small_gap);
+
+        let small_gap = small[2].y - (small[0].y + small[0].height);
+        let large_gap = large[2].y - (large[0].y + large[0].height);
+        assert_eq!(large_gap, 2 * small_gap);
+    }
+
+    #[test]
+    fn spacing_pct_never_collapses_cells() {
+        let area = Rect::new(0, 0, 20, 20);
+        let result = auto_grid_spacing_pct(area, 9, 100, 100);
+
+        assert_eq!(result.len(), 9);
+        for rect in result {
+            assert!(rect.width > 0);
+            assert!(rect.height > 0);
+        }
+    }
+
+    #[test]
+    fn fit_spacing_spans_the_area() {
+        let area = Rect::new(3, 2, 47, 23);
+        let (cells, h_spacing, v_spacing) = auto_grid_fit_spacing(area, 7, 10, 5);
+
+        assert_eq!((h_spacing, v_spacing), (8, 4));
+        assert_eq!(cells[0], Rect::new(3, 2, 11, 5));
+        assert_eq!(cells[1].x - cells[0].right(), h_spacing);
+        assert_eq!(cells[2].right(), area.right());
+        assert_eq!(cells[3].y - cells[0].bottom(), v_spacing);
+        assert_eq!(cells[6].bottom(), area.bottom());
+        assert!(
+            cells
+                .iter()
+                .all(|cell| cell.width >= 10 && cell.height == 5)
+        );
+    }
+
+    #[test]
+    fn fit_spacing_without_room_is_empty() {
+        let area = Rect::new(0, 0, 29, 30);
+        assert_eq!(auto_grid_fit_spacing(area, 9, 10, 5), (Vec::new(), 0, 0));
+        assert_eq!(auto_grid_fit_spacing(area, 0, 1, 1), (Vec::new(), 0, 0));
+
+        let (cells, h_spacing, v_spacing) = auto_grid_fit_spacing(area, 1, 20, 30);
+        assert_eq!((cells, h_spacing, v_spacing), (vec![area], 0, 0));
+    }
+
+    #[test]
+    fn max_ratio_trades_rows_for_columns() {
+        let area = Rect::new(0, 0, 200, 12);
+        let default = auto_grid(area, 9, 1);
+        assert!(default[0].width > 3 * default[0].height);
+
+        let cells = auto_grid_max_ratio(area, 9, 1, 3.0);
+        assert_eq!(cells, grid_cells(area, 9, (1, 9), 1, 1));
+        let rows = |cells: &[Rect]| cells.iter().filter(|cell| cell.x == 0).count();
+        assert!(rows(&cells) < rows(&default));
+        assert!(cells.iter().all(|cell| cell.width <= 3 * cell.height));
+
+        // Already narrow enough.
+        assert_eq!(auto_grid_max_ratio(area, 9, 1, 25.0), default);
+    }
+
+    #[test]
+    fn max_ratio_falls_back_to_the_narrowest_cells() {
+        let area = Rect::new(0, 0, 200, 2);
+        let cells = auto_grid_max_ratio(area, 4, 0, 1.0);
+        assert_eq!(cells, grid_cells(area, 4, (1, 4), 0, 0));
+
+        // A single row would leave the cells without width.
+        let area = Rect::new(0, 0, 6, 6);
+        let cells = auto_grid_max_ratio(area, 9, 0, 0.0);
+        assert_eq!(cells, grid_cells(area, 9, (2, 5), 0, 0));
+        assert!(auto_grid_max_ratio(area, 0, 0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn fractional_cells_split_the_area_exactly() {
+        let area = Rect::new(3, 2, 100, 50);
+        let cells = auto_grid_f32(area, 7, 0);
+
+        assert_eq!(cells.len(), 7);
+        for (index, &(x, y, width, height)) in cells.iter().enumerate() {
+            assert_eq!(width, 100.0 / 3.0);
+            assert_eq!(height, 50.0 / 3.0);
+            assert!((x - (3.0 + (index % 3) as f32 * width)).abs() < 1e-4);
+            assert!((y - (2.0 + (index / 3) as f32 * height)).abs() < 1e-4);
+        }
+
+        let cells = auto_grid_f32(area, 4, 3);
+        let (x, _, width, _) = cells[1];
+        assert!((x + width - 103.0).abs() < 1e-4);
+        assert!((2.0 * width + 3.0 - 100.0).abs() < 1e-4);
+        assert!(auto_grid_f32(area, 0, 3).is_empty());
+        assert_eq!(
+            auto_grid_f32(Rect::new(0, 0, 2, 2), 4, 5)[3],
+            (0.0, 0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn grid_fits_checks_every_cell() {
+        // 3 columns of 33 or 34, and 3 rows of 10.
+        let area = Rect::new(5, 5, 102, 32);
+        assert!(grid_fits(area, 7, 1, 33, 10, 3.4));
+        assert!(!grid_fits(area, 7, 1, 34, 10, 3.4));
+        assert!(!grid_fits(area, 7, 1, 33, 11, 3.4));
+        assert!(!grid_fits(area, 7, 1, 33, 10, 3.3));
+
+        assert!(grid_fits(area, 0, 1, 200, 200, 0.0));
+        assert!(!grid_fits(Rect::new(0, 0, 2, 2), 9, 0, 0, 0, 10.0));
+        assert!(!grid_fits(area, MAX_CELLS + 1, 0, 0, 0, 10.0));
+    }
+
+    #[test]
+    fn max_ratio_and_grid_fits_agree() {
+        let measured = |cells: &[Rect]| {
+            let width = cells.iter().map(|cell| cell.width).max().unwrap();
+            let height = cells.iter().map(|cell| cell.height).min().unwrap();
+            f32::from(width) / f32::from(height)
+        };
+
+        for (area, n, spacing) in [
+            // 3 columns of 33 or 34 and 3 rows of 10, between 3.3 and 3.4.
+            (Rect::new(5, 5, 102, 32), 7, 1),
+            (Rect::new(0, 0, 120, 30), 9, 0),
+            (Rect::new(0, 0, 80, 24), 5, 2),
+        ] {
+            let cells = auto_grid(area, n, spacing);
+            for max_ratio in [1.0, 2.0, 3.3, 3.35, 3.4, 4.0, 8.0] {
+                let fits = grid_fits(area, n, spacing, 1, 1, max_ratio);
+                assert_eq!(
+                    fits,
+                    measured(&cells) <= max_ratio,
+                    "{area}, n {n}, {max_ratio}"
+                );
+                if fits {
+                    assert_eq!(auto_grid_max_ratio(area, n, spacing, max_ratio), cells);
+                }
+            }
+        }
+
+        // Narrowest cells at 3.3 but widest at 3.4, so the rows are traded for columns.
+        let area = Rect::new(5, 5, 102, 32);
+        let cells = auto_grid_max_ratio(area, 7, 1, 3.35);
+        assert_ne!(cells, auto_grid(area, 7, 1));
+        assert!(measured(&cells) <= 3.35);
+    }
+
+    #[test]
+    fn letterbox_is_centered_and_inside_the_cell() {
+        for cell in [
+            Rect::new(0, 0, 40, 10),
+            Rect::new(5, 3, 17, 30),
+            Rect::new(2, 2, 41, 31),
+        ] {
+            let inner = letterbox(cell, 4, 3);
+            assert_eq!(inner.intersection(cell), inner);
+            assert!(inner.width == cell.width || inner.height == cell.height);
+            // Within rounding of 4:3, and the margins differ by at most one.
+            assert!(u32::from(inner.width) * 3 <= u32::from(inner.height) * 4 + 4);
+            let (left, right) = (inner.x - cell.x, cell.right() - inner.right());
+            let (top, bottom) = (inner.y - cell.y, cell.bottom() - inner.bottom());
+            assert!(right - left <= 1 && bottom - top <= 1, "{cell}");
+        }
+    }
+
+    #[test]
+    fn letterbox_too_small_is_empty() {
+        assert_eq!(
+            letterbox(Rect::new(3, 4, 1, 9), 4, 3),
+            Rect::new(3, 4, 0, 0)
+        );
+        assert_eq!(
+            letterbox(Rect::new(3, 4, 9, 9), 0, 3),
+            Rect::new(3, 4, 0, 0)
+        );
+        assert_eq!(
+            letterbox(Rect::new(3, 4, 4, 3), 4, 3),
+            Rect::new(3, 4, 4, 3)
+        );
+
+        let cells = auto_grid_letterboxed(Rect::new(0, 0, 3, 1), 5, 0, (1, 1));
+        assert_eq!(cells.len(), 5);
+        assert_eq!(cells[4], (Rect::ZERO, Rect::ZERO));
+    }
 }
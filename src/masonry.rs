@@ -0,0 +1,114 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+use crate::{collapse_empty, new_rect, split_grid};
+
+/// Arranges items of varying heights in `cols` columns, Pinterest-style.
+///
+/// Each item, in order, is stacked at the bottom of the currently shortest column (the leftmost one
+/// on ties) with the given height. Columns share the width of `area` equally, separated by
+/// `h_spacing`, and items in a column are separated by `v_spacing`. A `cols` of 0 is treated as 1.
+///
+/// Items extending past the bottom of `area` are clipped to it. Items starting below it, like
+/// items with a height of 0 or in columns without room, come back as zero-size rects at the origin
+/// of `area`.
+///
+/// # Returns
+///
+/// One rect per height, in the same order as `heights`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::masonry;
+///
+/// let area = Rect::new(0, 0, 20, 30);
+/// let cells = masonry(area, &[10, 3, 4], 2, 0, 0);
+///
+/// assert_eq!(cells[0], Rect::new(0, 0, 10, 10));
+/// assert_eq!(cells[1], Rect::new(10, 0, 10, 3));
+/// // The second column is the shortest.
+/// assert_eq!(cells[2], Rect::new(10, 3, 10, 4));
+/// ```
+pub fn masonry(
+    area: Rect,
+    heights: &[u16],
+    cols: u16,
+    h_spacing: u16,
+    v_spacing: u16,
+) -> Vec<Rect> {
+    if heights.is_empty() {
+        return Vec::new();
+    }
+
+    let columns = split_grid(area, 1, cols.max(1), h_spacing, 0);
+    let mut offsets = vec![0u32; columns.len()];
+
+    let mut out: Vec<Rect> = heights
+        .iter()
+        .map(|&height| {
+            let (col, offset) = offsets
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, &offset)| offset)
+                .map(|(col, &offset)| (col, offset))
+                .expect("at least one column");
+            offsets[col] = offset + u32::from(height) + u32::from(v_spacing);
+
+            let column = columns[col];
+            let y = (u32::from(area.y) + offset).min(u32::from(area.bottom())) as u16;
+            let height = height.min(area.bottom() - y);
+            new_rect(column.x, y, column.width, height)
+        })
+        .collect();
+    collapse_empty(area, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assigns_shortest_column() {
+        let area = Rect::new(0, 0, 30, 100);
+        let heights = [3, 12, 5, 4, 2, 6];
+        let result = masonry(area, &heights, 3, 0, 1);
+
+        assert_eq!(result.len(), heights.len());
+        // Columns 0, 1, 2 first, then the shortest: column 0 (4), column 2 (6), column 0 (9).
+        assert_eq!(result[0], Rect::new(0, 0, 10, 3));
+        assert_eq!(result[1], Rect::new(10, 0, 10, 12));
+        assert_eq!(result[2], Rect::new(20, 0, 10, 5));
+        assert_eq!(result[3], Rect::new(0, 4, 10, 4));
+        assert_eq!(result[4], Rect::new(20, 6, 10, 2));
+        assert_eq!(result[5], Rect::new(0, 9, 10, 6));
+    }
+
+    #[test]
+    fn clips_to_area() {
+        let area = Rect::new(0, 10, 10, 10);
+        let result = masonry(area, &[8, 8, 8], 1, 0, 0);
+
+        assert_eq!(result[0], Rect::new(0, 10, 10, 8));
+        assert_eq!(result[1], Rect::new(0, 18, 10, 2));
+        assert_eq!(result[2], Rect::new(0, 10, 0, 0));
+    }
+
+    #[test]
+    fn items_without_room_collapse_to_the_origin() {
+        let area = Rect::new(3, 4, 1, 10);
+        // 1 cell wide split in 2 columns: only the first one gets room.
+        let result = masonry(area, &[2, 2, 0, 20, 5], 2, 0, 0);
+
+        assert_eq!(result[0], Rect::new(3, 4, 1, 2));
+        assert_eq!(result[1], Rect::new(3, 4, 0, 0));
+        assert_eq!(result[2], Rect::new(3, 4, 0, 0));
+        assert_eq!(result[3], Rect::new(3, 6, 1, 8));
+        assert_eq!(result[4], Rect::new(3, 4, 0, 0));
+    }
+}
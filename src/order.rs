@@ -0,0 +1,347 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+use crate::{auto_grid, auto_grid_full, dimensions, empty_cell, grid_cells};
+
+/// Arranges `n` items in an automatic grid layout, filling cells in a clockwise spiral starting
+/// from the center.
+///
+/// The grid geometry is the same as [`auto_grid`](crate::auto_grid), only the order of the
+/// returned cells differs. Index 0 is the center cell; when a dimension is even, the center is the
+/// top-left cell of the central block (row `(rows - 1) / 2`, column `(cols - 1) / 2`). The spiral
+/// then moves right, down, left and up, skipping positions outside of the grid.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, auto_grid_spiral};
+///
+/// let area = Rect::new(0, 0, 90, 90);
+/// let cells = auto_grid_spiral(area, 9, 0);
+/// assert_eq!(cells[0], auto_grid(area, 9, 0)[4]);
+/// ```
+pub fn auto_grid_spiral(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let (rows, cols) = dimensions(n);
+    let slots = grid_cells(
+        area,
+        usize::from(rows) * usize::from(cols),
+        (rows, cols),
+        spacing,
+        spacing,
+    );
+    let mut out: Vec<Rect> = spiral_positions(rows, cols)
+        .take(n)
+        .map(|(r, c)| slots[usize::from(r) * usize::from(cols) + usize::from(c)])
+        .collect();
+    out.resize(n, empty_cell(area));
+    out
+}
+
+/// Arranges `n` items in an automatic grid layout, filling cells along anti-diagonals.
+///
+/// The grid geometry is the same as [`auto_grid`](crate::auto_grid). Cells are ordered by
+/// `row + col`, then by row: `(0, 0)`, then `(0, 1), (1, 0)`, then `(0, 2), (1, 1), (2, 0)`, and
+/// so on. The empty trailing slots of a partial last row are skipped.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, auto_grid_diagonal};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let grid = auto_grid(area, 4, 0);
+/// assert_eq!(auto_grid_diagonal(area, 4, 0), vec![grid[0], grid[1], grid[2], grid[3]]);
+/// ```
+pub fn auto_grid_diagonal(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let (rows, cols) = dimensions(n);
+    let slots = grid_cells(area, n, (rows, cols), spacing, spacing);
+    let (rows, cols) = (usize::from(rows), usize::from(cols));
+
+    let mut out = Vec::with_capacity(n);
+    for diagonal in 0..rows + cols - 1 {
+        let first_row = diagonal.saturating_sub(cols - 1);
+        for r in first_row..=diagonal.min(rows - 1) {
+            let index = r * cols + (diagonal - r);
+            if index < n {
+                out.push(slots[index]);
+            }
+        }
+    }
+    out.resize(n, empty_cell(area));
+    out
+}
+
+/// Arranges `n` items in an automatic grid layout, filling columns top-to-bottom with balanced
+/// column lengths.
+///
+/// The grid geometry is the same as [`auto_grid`](crate::auto_grid), but cells are filled in
+/// column-major order and the items are spread so that column lengths differ by at most one, the
+/// longer columns coming first. For `n = 7` and 3 columns, the columns hold 3, 2 and 2 items rather
+/// than 3, 3 and 1. The empty slots are left at the bottom of the shorter columns. Items past
+/// [`MAX_CELLS`](crate::MAX_CELLS) have no slot and come back as a zero-size rect at the origin of
+/// `area`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_balanced_columns;
+///
+/// let area = Rect::new(0, 0, 90, 90);
+/// let cells = auto_grid_balanced_columns(area, 7, 0);
+/// // Column 0 holds items 0 to 2, column 1 items 3 and 4, column 2 items 5 and 6.
+/// assert_eq!(cells[2], Rect::new(0, 60, 30, 30));
+/// assert_eq!(cells[3], Rect::new(30, 0, 30, 30));
+/// assert_eq!(cells[5], Rect::new(60, 0, 30, 30));
+/// ```
+pub fn auto_grid_balanced_columns(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let (rows, cols) = dimensions(n);
+    let slots = auto_grid_full(area, usize::from(rows) * usize::from(cols), spacing).0;
+
+    // Items past `MAX_CELLS` have no slot in the grid, so they get an empty cell.
+    let filled = n.min(slots.len());
+    let cols = usize::from(cols);
+    let (base, longer) = (filled / cols, filled % cols);
+    let mut out: Vec<Rect> = (0..cols)
+        .flat_map(|c| {
+            let len = base + usize::from(c < longer);
+            (0..len).map(move |r| r * cols + c)
+        })
+        .map(|index| slots[index])
+        .collect();
+    out.resize(n, empty_cell(area));
+    out
+}
+
+/// Arranges one item per key in an automatic grid layout, placing the items in key order.
+///
+/// The grid geometry is the same as [`auto_grid`](crate::auto_grid). The item with the smallest
+/// key gets the top-left cell, and the others follow in row-major order by increasing key. Items
+/// with equal keys keep their relative order.
+///
+/// # Returns
+///
+/// One cell per key, in the same order as `keys`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, auto_grid_by_key};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let grid = auto_grid(area, 4, 0);
+/// // Lower priorities first.
+/// let cells = auto_grid_by_key(area, 0, &[3, 1, 2, 0]);
+/// assert_eq!(cells, vec![grid[3], grid[1], grid[2], grid[0]]);
+/// ```
+pub fn auto_grid_by_key<K: Ord>(area: Rect, spacing: u16, keys: &[K]) -> Vec<Rect> {
+    let slots = auto_grid(area, keys.len(), spacing);
+
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_by_key(|&index| &keys[index]);
+
+    let mut out = vec![Rect::default(); keys.len()];
+    for (slot, index) in slots.into_iter().zip(order) {
+        out[index] = slot;
+    }
+    out
+}
+
+/// Yields every `(row, col)` of a `rows * cols` grid in clockwise spiral order from the center.
+fn spiral_positions(rows: u16, cols: u16) -> impl Iterator<Item = (u16, u16)> {
+    const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    let total = usize::from(rows) * usize::from(cols);
+    let (mut r, mut c) = ((i32::from(rows) - 1) / 2, (i32::from(cols) - 1) / 2);
+    let (mut direction, mut run, mut step, mut runs) = (0, 1, 0, 0);
+    let mut emitted = 0;
+
+    core::iter::from_fn(move || {
+        while emitted < total {
+            let position = (r, c);
+
+            let (dr, dc) = DIRECTIONS[direction];
+            r += dr;
+            c += dc;
+            step += 1;
+            if step == run {
+                step = 0;
+                direction = (direction + 1) % 4;
+                runs += 1;
+                if runs % 2 == 0 {
+                    run += 1;
+                }
+            }
+
+            let (pr, pc) = position;
+            if (0..i32::from(rows)).contains(&pr) && (0..i32::from(cols)).contains(&pc) {
+                emitted += 1;
+                return Some((pr as u16, pc as u16));
+            }
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[test]
+    fn spiral_starts_at_center() {
+        let area = Rect::new(0, 0, 90, 90);
+        let grid = auto_grid(area, 9, 0);
+        let result = auto_grid_spiral(area, 9, 0);
+
+        // Center cell at (1, 1).
+        assert_eq!(result[0], grid[4]);
+        assert_eq!(result[0], Rect::new(30, 30, 30, 30));
+
+        // Clockwise: right, down, left, left, up, up, right, right.
+        let order = [4, 5, 8, 7, 6, 3, 0, 1, 2];
+        for (i, &slot) in order.iter().enumerate() {
+            assert_eq!(result[i], grid[slot], "spiral index {i}");
+        }
+    }
+
+    #[test]
+    fn spiral_even_dimensions() {
+        let area = Rect::new(0, 0, 100, 100);
+        let grid = auto_grid(area, 4, 0);
+        let result = auto_grid_spiral(area, 4, 0);
+
+        assert_eq!(result, vec![grid[0], grid[1], grid[3], grid[2]]);
+    }
+
+    #[test]
+    fn diagonal_order() {
+        let area = Rect::new(0, 0, 90, 90);
+        let grid = auto_grid(area, 9, 0);
+        let result = auto_grid_diagonal(area, 9, 0);
+
+        // (0,0), (0,1), (1,0), (0,2), (1,1), (2,0), (1,2), (2,1), (2,2)
+        let order = [0, 1, 3, 2, 4, 6, 5, 7, 8];
+        let expected: Vec<Rect> = order.iter().map(|&slot| grid[slot]).collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn diagonal_skips_empty_slots() {
+        let area = Rect::new(0, 0, 90, 90);
+        let grid = auto_grid(area, 7, 0);
+        let result = auto_grid_diagonal(area, 7, 0);
+
+        // (2,1) and (2,2) are the empty trailing slots.
+        let order = [0, 1, 3, 2, 4, 6, 5];
+        let expected: Vec<Rect> = order.iter().map(|&slot| grid[slot]).collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn balanced_column_counts() {
+        let area = Rect::new(0, 0, 90, 90);
+        let result = auto_grid_balanced_columns(area, 7, 0);
+        assert_eq!(result.len(), 7);
+
+        let count = |x| result.iter().filter(|cell| cell.x == x).count();
+        assert_eq!([count(0), count(30), count(60)], [3, 2, 2]);
+
+        // Column-major order.
+        let xs: Vec<u16> = result.iter().map(|cell| cell.x).collect();
+        assert_eq!(xs, [0, 0, 0, 30, 30, 60, 60]);
+        assert!(result[0].y < result[1].y && result[1].y < result[2].y);
+    }
+
+    #[test]
+    fn balanced_columns_use_grid_geometry() {
+        let area = Rect::new(5, 5, 100, 60);
+        for n in 1..=30 {
+            let result = auto_grid_balanced_columns(area, n, 1);
+            let (populated, empty) = auto_grid_full(area, n, 1);
+            let slots: Vec<Rect> = populated.into_iter().chain(empty).collect();
+
+            assert_eq!(result.len(), n);
+            assert!(result.iter().all(|cell| slots.contains(cell)));
+        }
+    }
+
+    #[test]
+    fn sorted_keys_fill_row_major() {
+        let area = Rect::new(2, 3, 90, 60);
+        let keys = ["e", "a", "d", "b", "f", "c"];
+        let grid = auto_grid(area, keys.len(), 1);
+        let result = auto_grid_by_key(area, 1, &keys);
+
+        let mut sorted = keys;
+        sorted.sort();
+        for (slot, key) in sorted.iter().enumerate() {
+            let index = keys.iter().position(|k| k == key).unwrap();
+            assert_eq!(result[index], grid[slot], "{key}");
+        }
+    }
+
+    #[test]
+    fn equal_keys_keep_their_order() {
+        let area = Rect::new(0, 0, 100, 100);
+        let grid = auto_grid(area, 4, 0);
+        let result = auto_grid_by_key(area, 0, &[1, 0, 1, 0]);
+        assert_eq!(result, vec![grid[2], grid[0], grid[3], grid[1]]);
+        assert!(auto_grid_by_key::<u8>(area, 0, &[]).is_empty());
+    }
+
+    #[test]
+    fn spiral_covers_every_slot_once() {
+        for n in 1..=30 {
+            let (rows, cols) = dimensions(n);
+            let mut positions: Vec<_> = spiral_positions(rows, cols).collect();
+            assert_eq!(positions.len(), usize::from(rows) * usize::from(cols));
+            positions.sort_unstable();
+            positions.dedup();
+            assert_eq!(positions.len(), usize::from(rows) * usize::from(cols));
+            assert_eq!(auto_grid_spiral(Rect::new(0, 0, 100, 100), n, 1).len(), n);
+        }
+    }
+
+    #[test]
+    fn orders_collapse_cells_without_room() {
+        let area = Rect::new(5, 5, 0, 10);
+        let expected = auto_grid(area, 4, 0);
+        assert_eq!(expected, [Rect::new(5, 5, 0, 0); 4]);
+        assert_eq!(auto_grid_spiral(area, 4, 0), expected);
+        assert_eq!(auto_grid_diagonal(area, 4, 0), expected);
+
+        // Only the first column gets room.
+        let area = Rect::new(5, 5, 1, 10);
+        let grid = auto_grid(area, 9, 1);
+        let mut spiral = auto_grid_spiral(area, 9, 1);
+        let mut diagonal = auto_grid_diagonal(area, 9, 1);
+        let mut sorted = grid.clone();
+        for cells in [&mut spiral, &mut diagonal, &mut sorted] {
+            cells.sort_unstable_by_key(|cell| (cell.y, cell.x, cell.width));
+        }
+        assert_eq!(spiral, sorted);
+        assert_eq!(diagonal, sorted);
+    }
+}
@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+use crate::new_rect;
+
+/// Packs items with preferred `(width, height)` sizes into rows, shelf-style.
+///
+/// Items are placed left to right, separated by `spacing`, until the next one doesn't fit in the
+/// remaining width; a new row then starts below the tallest item of the current row. An item wider
+/// than `area` gets a row of its own and is narrowed to the width of `area`. Items extending past
+/// the bottom of `area` are clipped to it, and items that don't fit at all get a zero-size rect at
+/// the origin of `area`.
+///
+/// # Returns
+///
+/// One rect per size, in the same order as `sizes`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::pack;
+///
+/// let area = Rect::new(0, 0, 20, 10);
+/// let cells = pack(area, &[(8, 2), (8, 3), (8, 1)], 1);
+///
+/// assert_eq!(cells[0], Rect::new(0, 0, 8, 2));
+/// assert_eq!(cells[1], Rect::new(9, 0, 8, 3));
+/// assert_eq!(cells[2], Rect::new(0, 4, 8, 1));
+/// ```
+pub fn pack(area: Rect, sizes: &[(u16, u16)], spacing: u16) -> Vec<Rect> {
+    let (left, right) = (u32::from(area.x), u32::from(area.right()));
+    let bottom = u32::from(area.bottom());
+    let spacing = u32::from(spacing);
+
+    let (mut x, mut y) = (left, u32::from(area.y));
+    let mut row_height = 0;
+
+    sizes
+        .iter()
+        .map(|&(width, height)| {
+            let width = u32::from(width).min(right - left);
+            let height = u32::from(height);
+
+            if x > left && x + width > right {
+                x = left;
+                y += row_height + spacing;
+                row_height = 0;
+            }
+
+            let cell = if y < bottom {
+                new_rect(
+                    x as u16,
+                    y as u16,
+                    width as u16,
+                    height.min(bottom - y) as u16,
+                )
+            } else {
+                new_rect(area.x, area.y, 0, 0)
+            };
+
+            x += width + spacing;
+            row_height = row_height.max(height);
+            cell
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_rows_greedily() {
+        let area = Rect::new(2, 3, 30, 20);
+        let result = pack(area, &[(10, 2), (10, 4), (9, 1), (5, 3), (20, 2)], 1);
+
+        assert_eq!(result[0], Rect::new(2, 3, 10, 2));
+        assert_eq!(result[1], Rect::new(13, 3, 10, 4));
+        // 24 + 9 > 32, new row below the tallest item.
+        assert_eq!(result[2], Rect::new(2, 8, 9, 1));
+        assert_eq!(result[3], Rect::new(12, 8, 5, 3));
+        assert_eq!(result[4], Rect::new(2, 12, 20, 2));
+    }
+
+    #[test]
+    fn over_wide_item_gets_its_own_row() {
+        let area = Rect::new(0, 0, 20, 20);
+        let result = pack(area, &[(5, 1), (50, 2), (5, 1)], 0);
+
+        assert_eq!(result[0], Rect::new(0, 0, 5, 1));
+        assert_eq!(result[1], Rect::new(0, 1, 20, 2));
+        assert_eq!(result[2], Rect::new(0, 3, 5, 1));
+    }
+
+    #[test]
+    fn items_past_the_bottom() {
+        let area = Rect::new(0, 0, 10, 5);
+        let result = pack(area, &[(10, 4), (10, 4), (10, 4)], 0);
+
+        assert_eq!(result[0], Rect::new(0, 0, 10, 4));
+        assert_eq!(result[1], Rect::new(0, 4, 10, 1));
+        assert_eq!(result[2], Rect::new(0, 0, 0, 0));
+    }
+}
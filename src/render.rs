@@ -0,0 +1,305 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+#[cfg(all(feature = "widget-ref", ratatui_widgets))]
+use ratatui::widgets::WidgetRef;
+
+use crate::auto_grid;
+
+/// Renders each widget into its cell of an automatic grid layout of `area`.
+///
+/// The grid is computed with [`auto_grid`] for as many items as `widgets` yields, and the widgets
+/// are rendered in row-major order. Widgets whose cell has a width or height of 0 are not
+/// rendered.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::text::Line;
+/// use ratatui_auto_grid::render_widgets;
+///
+/// let area = Rect::new(0, 0, 6, 1);
+/// let mut buf = Buffer::empty(area);
+/// render_widgets(&mut buf, area, ["a", "b"].map(Line::raw), 1);
+/// assert_eq!(buf, Buffer::with_lines(["a   b "]));
+/// ```
+pub fn render_widgets<W: Widget>(
+    buf: &mut Buffer,
+    area: Rect,
+    widgets: impl IntoIterator<Item = W>,
+    spacing: u16,
+) {
+    let widgets: Vec<W> = widgets.into_iter().collect();
+    let cells = auto_grid(area, widgets.len(), spacing);
+    for (widget, cell) in widgets.into_iter().zip(cells) {
+        if !cell.is_empty() {
+            widget.render(cell, buf);
+        }
+    }
+}
+
+/// Renders each widget by reference into its cell of an automatic grid layout of `area`.
+///
+/// This is [`render_widgets`] for widgets implementing [`WidgetRef`], so that they don't need to
+/// be cloned or consumed.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Paragraph;
+/// use ratatui_auto_grid::render_widgets_ref;
+///
+/// let area = Rect::new(0, 0, 6, 1);
+/// let widgets = ["a", "b"].map(Paragraph::new);
+/// let mut buf = Buffer::empty(area);
+/// render_widgets_ref(&mut buf, area, &widgets, 1);
+/// assert_eq!(buf, Buffer::with_lines(["a   b "]));
+/// ```
+#[cfg(all(feature = "widget-ref", ratatui_widgets))]
+pub fn render_widgets_ref<'a, W: WidgetRef + 'a>(
+    buf: &mut Buffer,
+    area: Rect,
+    widgets: impl IntoIterator<Item = &'a W>,
+    spacing: u16,
+) {
+    let widgets: Vec<&W> = widgets.into_iter().collect();
+    let cells = auto_grid(area, widgets.len(), spacing);
+    for (widget, cell) in widgets.into_iter().zip(cells) {
+        if !cell.is_empty() {
+            widget.render_ref(cell, buf);
+        }
+    }
+}
+
+/// Pairs each item of `items` with its cell of an automatic grid layout of `area`.
+///
+/// The grid is computed with [`auto_grid`] for `items.len()` items, so the items and the cells
+/// can't get out of sync, and the pairs come in row-major order. Unlike [`for_each_cell`],
+/// items whose cell has a width or height of 0 are yielded too. [`AutoGrid::zip`] does the same
+/// for a configured grid.
+///
+/// [`AutoGrid::zip`]: crate::AutoGrid::zip
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_zip;
+///
+/// let items = ["cpu", "mem", "net"];
+/// let mut pairs = auto_grid_zip(Rect::new(0, 0, 20, 10), &items, 0);
+/// assert_eq!(pairs.len(), 3);
+/// assert_eq!(pairs.next(), Some((&"cpu", Rect::new(0, 0, 10, 5))));
+/// ```
+pub fn auto_grid_zip<T>(
+    area: Rect,
+    items: &[T],
+    spacing: u16,
+) -> impl ExactSizeIterator<Item = (&T, Rect)> {
+    items.iter().zip(auto_grid(area, items.len(), spacing))
+}
+
+/// Pairs each item of `items`, mutably, with its cell of an automatic grid layout of `area`.
+///
+/// This is [`auto_grid_zip`] for items that need to be updated with their cell.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_zip_mut;
+///
+/// let mut widths = [0; 4];
+/// for (width, cell) in auto_grid_zip_mut(Rect::new(0, 0, 21, 10), &mut widths, 1) {
+///     *width = cell.width;
+/// }
+/// assert_eq!(widths, [10; 4]);
+/// ```
+pub fn auto_grid_zip_mut<T>(
+    area: Rect,
+    items: &mut [T],
+    spacing: u16,
+) -> impl ExactSizeIterator<Item = (&mut T, Rect)> {
+    let cells = auto_grid(area, items.len(), spacing);
+    items.iter_mut().zip(cells)
+}
+
+/// Calls `f` with the index and cell of each of `n` items laid out with [`auto_grid`].
+///
+/// The cells are visited in row-major order. Items whose cell has a width or height of 0 are
+/// skipped, since rendering into an empty area panics in some widgets, so `f` may be called fewer
+/// than `n` times.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::text::Line;
+/// use ratatui::widgets::Widget;
+/// use ratatui_auto_grid::for_each_cell;
+///
+/// let area = Rect::new(0, 0, 4, 2);
+/// let mut buf = Buffer::empty(area);
+/// for_each_cell(area, 4, 0, |i, cell| {
+///     Line::raw(i.to_string()).render(cell, &mut buf);
+/// });
+/// assert_eq!(buf, Buffer::with_lines(["0 1 ", "2 3 "]));
+/// ```
+pub fn for_each_cell(area: Rect, n: usize, spacing: u16, mut f: impl FnMut(usize, Rect)) {
+    let result: Result<(), Infallible> = try_for_each_cell(area, n, spacing, |index, cell| {
+        f(index, cell);
+        Ok(())
+    });
+    let Ok(()) = result;
+}
+
+/// Calls `f` with the index and cell of each of `n` items laid out with [`auto_grid`], stopping at
+/// the first error.
+///
+/// Like [`for_each_cell`], the cells are visited in row-major order and zero-size cells are
+/// skipped.
+///
+/// # Errors
+///
+/// Returns the first error returned by `f`, without visiting the remaining cells.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::try_for_each_cell;
+///
+/// let mut visited = Vec::new();
+/// let result = try_for_each_cell(Rect::new(0, 0, 10, 10), 4, 0, |i, _| {
+///     if i == 2 {
+///         return Err("stopped at 2");
+///     }
+///     visited.push(i);
+///     Ok(())
+/// });
+/// assert_eq!(result, Err("stopped at 2"));
+/// assert_eq!(visited, [0, 1]);
+/// ```
+pub fn try_for_each_cell<E>(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    mut f: impl FnMut(usize, Rect) -> Result<(), E>,
+) -> Result<(), E> {
+    auto_grid(area, n, spacing)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, cell)| !cell.is_empty())
+        .try_for_each(|(index, cell)| f(index, cell))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_into_each_cell() {
+        let area = Rect::new(0, 0, 9, 7);
+        let mut buf = Buffer::empty(area);
+        render_widgets(&mut buf, area, ["a", "b", "c"], 1);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "a    b   ",
+                "         ",
+                "         ",
+                "         ",
+                "c        ",
+                "         ",
+                "         ",
+            ])
+        );
+    }
+
+    #[test]
+    fn zip_pairs_every_item_with_its_cell() {
+        let area = Rect::new(3, 1, 2, 1);
+        let mut items = [10, 11, 12, 13];
+        let cells = auto_grid(area, items.len(), 0);
+
+        let pairs: Vec<_> = auto_grid_zip(area, &items, 0).collect();
+        assert_eq!(pairs.len(), 4);
+        for (i, (item, cell)) in pairs.into_iter().enumerate() {
+            assert_eq!((*item, cell), (10 + i, cells[i]));
+        }
+
+        for (item, cell) in auto_grid_zip_mut(area, &mut items, 0) {
+            *item += usize::from(cell.width);
+        }
+        assert_eq!(items, [11, 12, 12, 13]);
+    }
+
+    #[test]
+    fn skips_zero_size_cells() {
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        // Only 2 of the 4 cells get room, the others would render at the origin.
+        render_widgets(&mut buf, area, ["a", "b", "c", "d"], 0);
+        assert_eq!(buf, Buffer::with_lines(["ab"]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "widget-ref", ratatui_widgets))]
+    fn renders_by_reference() {
+        use ratatui::widgets::{Block, Borders};
+
+        let area = Rect::new(0, 0, 9, 6);
+        let blocks = [(); 3].map(|_| Block::new().borders(Borders::ALL));
+
+        let (mut by_ref, mut by_value) = (Buffer::empty(area), Buffer::empty(area));
+        render_widgets_ref(&mut by_ref, area, &blocks, 1);
+        render_widgets(&mut by_value, area, blocks, 1);
+        assert_eq!(by_ref, by_value);
+    }
+
+    #[test]
+    fn for_each_cell_skips_zero_size_cells() {
+        let area = Rect::new(4, 2, 3, 1);
+        let mut visited = Vec::new();
+        for_each_cell(area, 5, 0, |index, cell| visited.push((index, cell)));
+
+        // 3 columns of 1 on the first row, none left for the second.
+        assert_eq!(
+            visited,
+            [
+                (0, Rect::new(4, 2, 1, 1)),
+                (1, Rect::new(5, 2, 1, 1)),
+                (2, Rect::new(6, 2, 1, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn try_for_each_cell_visits_in_fill_order() {
+        let area = Rect::new(0, 0, 30, 30);
+        let mut visited = Vec::new();
+        let result: Result<(), ()> = try_for_each_cell(area, 7, 1, |index, cell| {
+            visited.push((index, cell));
+            Ok(())
+        });
+
+        assert_eq!(result, Ok(()));
+        let expected: Vec<_> = auto_grid(area, 7, 1).into_iter().enumerate().collect();
+        assert_eq!(visited, expected);
+    }
+}
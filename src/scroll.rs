@@ -0,0 +1,796 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ratatui::layout::Rect;
+
+use crate::fast::{axis_track, split_axis};
+use crate::{collapse_empty, dimensions, empty_cell, grid_cells, new_rect};
+
+/// Arranges `n` items in a scrollable grid of rows with a fixed height.
+///
+/// Columns are picked like [`auto_grid`](crate::auto_grid) and share the width of `area`, but every
+/// row is `min_h` tall regardless of the height of `area`, so the grid may extend below it. The
+/// grid is then shifted up by `scroll_offset`: cells partly scrolled off the top are cut at
+/// `area.y`, and cells entirely above it get a height of 0. Cells below `area` are not clipped, so
+/// they should be intersected with `area` before rendering.
+///
+/// # Returns
+///
+/// One cell per item in row-major order, and the total height of the content, which is the
+/// scroll range to show in a scrollbar.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_scroll;
+///
+/// let area = Rect::new(0, 0, 40, 10);
+/// let (cells, height) = auto_grid_scroll(area, 8, 1, 5, 3);
+///
+/// // 3 rows of 5 with 1 cell between them.
+/// assert_eq!(height, 17);
+/// assert_eq!(cells[0], Rect::new(0, 0, 13, 2));
+/// assert_eq!(cells[3], Rect::new(0, 3, 13, 5));
+/// ```
+pub fn auto_grid_scroll(
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    min_h: u16,
+    scroll_offset: u16,
+) -> (Vec<Rect>, u16) {
+    if n == 0 {
+        return (Vec::new(), 0);
+    }
+
+    let (_, cols) = dimensions(n);
+    let rows = n.div_ceil(usize::from(cols)) as u64;
+    let pitch = u64::from(min_h) + u64::from(spacing);
+    let total = (rows * pitch - u64::from(spacing)).min(u64::from(u16::MAX)) as u16;
+
+    let columns: Vec<_> = split_axis(area.x, area.width, cols, spacing).collect();
+    let offset = u64::from(scroll_offset);
+    let mut out: Vec<Rect> = (0..rows)
+        .flat_map(|row| {
+            let top = row * pitch;
+            let visible_top = top.max(offset);
+            let y = (u64::from(area.y) + visible_top - offset).min(u64::from(u16::MAX)) as u16;
+            let height = (top + u64::from(min_h)).saturating_sub(visible_top) as u16;
+            columns
+                .iter()
+                .map(move |&(x, width)| new_rect(x, y, width, height))
+        })
+        .take(n)
+        .collect();
+    collapse_empty(area, &mut out);
+    (out, total)
+}
+
+/// Arranges `n` items in an automatic grid layout of `virtual_area`, keeping only the cells visible
+/// in `viewport`.
+///
+/// The grid is computed over the whole `virtual_area` like [`auto_grid`](crate::auto_grid), and
+/// each cell overlapping `viewport` is clipped to it. Cells outside of `viewport` and zero-size
+/// cells are left out.
+///
+/// # Returns
+///
+/// The index of each visible item with its clipped cell, in row-major order.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_clipped;
+///
+/// let virtual_area = Rect::new(0, 0, 40, 40);
+/// let visible = auto_grid_clipped(virtual_area, Rect::new(0, 10, 40, 20), 4, 0);
+/// assert_eq!(
+///     visible,
+///     [
+///         (0, Rect::new(0, 10, 20, 10)),
+///         (1, Rect::new(20, 10, 20, 10)),
+///         (2, Rect::new(0, 20, 20, 10)),
+///         (3, Rect::new(20, 20, 20, 10)),
+///     ]
+/// );
+/// ```
+pub fn auto_grid_clipped(
+    virtual_area: Rect,
+    viewport: Rect,
+    n: usize,
+    spacing: u16,
+) -> Vec<(usize, Rect)> {
+    grid_cells(virtual_area, n, dimensions(n), spacing, spacing)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, cell)| {
+            let clipped = cell.intersection(viewport);
+            (!clipped.is_empty()).then_some((index, clipped))
+        })
+        .collect()
+}
+
+/// Arranges one page of `n` items in a grid sized for `per_page` items.
+///
+/// The grid is always the one [`auto_grid`](crate::auto_grid) gives for `per_page` items, so cells
+/// keep the same size whatever the number of items. The items are split into pages of `per_page`,
+/// and the cells of the items on page `page` are returned, in row-major order from the first slot.
+/// The last page returns only as many cells as there are items left on it, and pages past the end
+/// return none.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_fixed_density;
+///
+/// let area = Rect::new(0, 0, 40, 30);
+/// // Pages of 12 items in a 3×4 grid: 12, 12 and then 6 items.
+/// assert_eq!(auto_grid_fixed_density(area, 0, 12, 30, 1).len(), 12);
+/// let last = auto_grid_fixed_density(area, 0, 12, 30, 2);
+/// assert_eq!(last.len(), 6);
+/// assert_eq!(last[5], Rect::new(10, 10, 10, 10));
+/// ```
+pub fn auto_grid_fixed_density(
+    area: Rect,
+    spacing: u16,
+    per_page: usize,
+    n: usize,
+    page: usize,
+) -> Vec<Rect> {
+    let first = page.saturating_mul(per_page);
+    let count = n.saturating_sub(first).min(per_page);
+
+    let mut out = grid_cells(area, per_page, dimensions(per_page), spacing, spacing);
+    out.truncate(count);
+    out
+}
+
+/// The visible page of a grid scrolled by whole rows.
+///
+/// Columns are picked like [`auto_grid`](crate::auto_grid). As many rows as fit in `area` at
+/// `min_h` are shown, and they share the height of `area` evenly. The row offset is clamped so
+/// that the last page is always full: scrolling past the end shows the last rows.
+///
+/// The values map directly to ratatui's `ScrollbarState`: [`ScrollableGrid::max_row_offset`] + 1
+/// as the content length and [`ScrollableGrid::row_offset`] as the position.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::ScrollableGrid;
+///
+/// // 100 items in 10 columns, with room for 4 rows.
+/// let grid = ScrollableGrid::new(Rect::new(0, 0, 100, 20), 100, 0, 5, 99);
+///
+/// assert_eq!(grid.total_rows(), 10);
+/// assert_eq!(grid.visible_rows(), 4);
+/// assert_eq!(grid.row_offset(), 6);
+/// assert_eq!(grid.cells()[0], (60, Rect::new(0, 0, 10, 5)));
+/// assert_eq!(grid.cells().len(), 40);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrollableGrid {
+    cells: Vec<(usize, Rect)>,
+    total_rows: usize,
+    visible_rows: usize,
+    row_offset: usize,
+}
+
+impl ScrollableGrid {
+    /// Lays out the page of `n` items starting at `row_offset`, with rows at least `min_h` tall.
+    ///
+    /// At least one row is shown, even if `area` is shorter than `min_h`.
+    pub fn new(area: Rect, n: usize, spacing: u16, min_h: u16, row_offset: usize) -> Self {
+        if n == 0 {
+            return Self {
+                cells: Vec::new(),
+                total_rows: 0,
+                visible_rows: 0,
+                row_offset: 0,
+            };
+        }
+
+        let (_, cols) = dimensions(n);
+        let cols = usize::from(cols);
+        let total_rows = n.div_ceil(cols);
+
+        let pitch = usize::from(min_h.max(1)) + usize::from(spacing);
+        let fitting = (usize::from(area.height) + usize::from(spacing)) / pitch;
+        let visible_rows = fitting.clamp(1, total_rows);
+        let row_offset = row_offset.min(total_rows - visible_rows);
+
+        let first = row_offset * cols;
+        let count = (visible_rows * cols).min(n - first);
+        let page = grid_cells(
+            area,
+            count,
+            (visible_rows as u16, cols as u16),
+            spacing,
+            spacing,
+        );
+
+        Self {
+            cells: (first..).zip(page).collect(),
+            total_rows,
+            visible_rows,
+            row_offset,
+        }
+    }
+
+    /// Returns the visible cells with the index of their item, in row-major order.
+    pub fn cells(&self) -> &[(usize, Rect)] {
+        &self.cells
+    }
+
+    /// Returns the number of rows of the whole grid.
+    pub fn total_rows(&self) -> usize {
+        self.total_rows
+    }
+
+    /// Returns the number of rows shown at once.
+    pub fn visible_rows(&self) -> usize {
+        self.visible_rows
+    }
+
+    /// Returns the index of the first visible row, after clamping.
+    pub fn row_offset(&self) -> usize {
+        self.row_offset
+    }
+
+    /// Returns the largest row offset, which shows the last page.
+    pub fn max_row_offset(&self) -> usize {
+        self.total_rows - self.visible_rows
+    }
+}
+
+/// How a [`VirtualGrid`] picks its columns and the number of rows shown at once.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VirtualLayout {
+    /// The columns of [`auto_grid`](crate::auto_grid), with every row shown. The cells are the same
+    /// as the ones of [`auto_grid`](crate::auto_grid).
+    #[default]
+    Auto,
+    /// `cols` columns, showing as many rows at least `min_h` tall as fit. A `cols` of 0 is treated
+    /// as 1.
+    Columns {
+        /// The number of columns.
+        cols: u16,
+        /// The minimum height of a row.
+        min_h: u16,
+    },
+    /// As many columns at least `min_w` wide as fit, showing as many rows at least `min_h` tall as
+    /// fit, like a file browser.
+    MinCellSize {
+        /// The minimum width of a column.
+        min_w: u16,
+        /// The minimum height of a row.
+        min_h: u16,
+    },
+}
+
+/// A grid of many items scrolled by whole rows, whose cells are computed on demand.
+///
+/// Unlike [`ScrollableGrid`], nothing is laid out upfront: [`VirtualGrid::rect_for`] computes the
+/// cell of a single item in constant time, so the cost of a frame only depends on the number of
+/// visible items. The visible rows share the height of the area evenly, and scroll offsets are
+/// clamped so that the last page is always full.
+///
+/// The first rows can be made sticky with [`VirtualGrid::with_sticky_rows`], for column headers:
+/// they stay at the top of the area and only the rows below them scroll.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{VirtualGrid, VirtualLayout};
+///
+/// let layout = VirtualLayout::MinCellSize { min_w: 10, min_h: 3 };
+/// let grid = VirtualGrid::new(Rect::new(0, 0, 80, 24), 50_000, 0, layout);
+///
+/// assert_eq!(grid.cols(), 8);
+/// assert_eq!(grid.content_height(), 6250);
+/// assert_eq!(grid.visible_range(100), 800..864);
+/// assert_eq!(grid.rect_for(801, 100), Some(Rect::new(10, 0, 10, 3)));
+/// assert_eq!(grid.rect_for(0, 100), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VirtualGrid {
+    area: Rect,
+    n: usize,
+    spacing: u16,
+    cols: u16,
+    total_rows: usize,
+    visible_rows: u16,
+    sticky_rows: u16,
+}
+
+impl VirtualGrid {
+    /// Creates a grid of `n` items in `area`, with `spacing` between cells.
+    pub fn new(area: Rect, n: usize, spacing: u16, layout: VirtualLayout) -> Self {
+        let (cols, min_h) = match layout {
+            VirtualLayout::Auto => (dimensions(n).1.max(1), None),
+            VirtualLayout::Columns { cols, min_h } => (cols.max(1), Some(min_h)),
+            VirtualLayout::MinCellSize { min_w, min_h } => {
+                let pitch = u32::from(min_w.max(1)) + u32::from(spacing);
+                let fitting = (u32::from(area.width) + u32::from(spacing)) / pitch;
+                (fitting.clamp(1, u32::from(u16::MAX)) as u16, Some(min_h))
+            }
+        };
+
+        let total_rows = n.div_ceil(usize::from(cols));
+        let visible_rows = match min_h {
+            None => total_rows,
+            Some(min_h) => {
+                let pitch = usize::from(min_h.max(1)) + usize::from(spacing);
+                let fitting = (usize::from(area.height) + usize::from(spacing)) / pitch;
+                fitting.clamp(1, total_rows.max(1))
+            }
+        };
+
+        Self {
+            area,
+            n,
+            spacing,
+            cols,
+            total_rows,
+            visible_rows: visible_rows.min(usize::from(u16::MAX)) as u16,
+            sticky_rows: 0,
+        }
+    }
+
+    /// Makes the first `rows` rows sticky.
+    ///
+    /// Sticky rows are always shown at the top of the area, and the scroll offset moves the rows
+    /// below them. When the grid doesn't fit in the area, at least one visible row is left to
+    /// scroll, so fewer rows may stay sticky than asked for.
+    pub fn with_sticky_rows(mut self, rows: u16) -> Self {
+        let scrolling = self.total_rows > usize::from(self.visible_rows);
+        let max = self.visible_rows - u16::from(scrolling && self.visible_rows > 0);
+        self.sticky_rows = rows.min(max);
+        self
+    }
+
+    /// Returns the number of sticky rows.
+    pub fn sticky_rows(&self) -> u16 {
+        self.sticky_rows
+    }
+
+    /// Returns the indices of the items in the sticky rows, which are visible at any offset.
+    pub fn sticky_range(&self) -> Range<usize> {
+        0..(usize::from(self.sticky_rows) * usize::from(self.cols)).min(self.n)
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> u16 {
+        self.cols
+    }
+
+    /// Returns the number of rows of the whole grid, the content length of a scrollbar.
+    pub fn content_height(&self) -> usize {
+        self.total_rows
+    }
+
+    /// Returns the number of rows shown at once.
+    pub fn visible_rows(&self) -> u16 {
+        self.visible_rows
+    }
+
+    /// Returns the largest scroll offset, in rows, which shows the last page.
+    pub fn max_scroll_offset(&self) -> usize {
+        self.total_rows
+            .saturating_sub(usize::from(self.visible_rows))
+    }
+
+    /// Returns the indices of the scrolling items visible when scrolled by `scroll_offset` rows.
+    ///
+    /// The items of the sticky rows are not included, see [`VirtualGrid::sticky_range`].
+    pub fn visible_range(&self, scroll_offset: usize) -> Range<usize> {
+        let cols = usize::from(self.cols);
+        let sticky = usize::from(self.sticky_rows);
+        let first = (sticky + scroll_offset.min(self.max_scroll_offset())) * cols;
+        let last = first + usize::from(self.visible_rows - self.sticky_rows) * cols;
+        first.min(self.n)..last.min(self.n)
+    }
+
+    /// Returns the scroll offset closest to `scroll_offset` that shows the item at `index`.
+    ///
+    /// The offset only changes as much as needed: by one row when the item is on the row just
+    /// below or above the visible ones, as when moving a selection with the keyboard, and straight
+    /// to the row of the item when it is further away. The result is clamped to
+    /// [`VirtualGrid::max_scroll_offset`], and items of the sticky rows or past the last item
+    /// leave the clamped offset unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui_auto_grid::ratatui;
+    /// use ratatui::layout::Rect;
+    /// use ratatui_auto_grid::{VirtualGrid, VirtualLayout};
+    ///
+    /// // 10 columns with 4 visible rows, the first one sticky.
+    /// let layout = VirtualLayout::Columns { cols: 10, min_h: 5 };
+    /// let grid = VirtualGrid::new(Rect::new(0, 0, 100, 20), 1000, 0, layout).with_sticky_rows(1);
+    ///
+    /// // Rows 1 to 3 are visible, so moving down to row 4 scrolls by one.
+    /// assert_eq!(grid.scroll_to(45, 0), 1);
+    /// assert_eq!(grid.scroll_to(505, 0), 47);
+    /// assert_eq!(grid.scroll_to(5, 47), 47);
+    /// ```
+    pub fn scroll_to(&self, index: usize, scroll_offset: usize) -> usize {
+        let scroll_offset = scroll_offset.min(self.max_scroll_offset());
+        let window = usize::from(self.visible_rows - self.sticky_rows);
+        if index >= self.n || self.sticky_range().contains(&index) || window == 0 {
+            return scroll_offset;
+        }
+
+        let row = index / usize::from(self.cols) - usize::from(self.sticky_rows);
+        if row < scroll_offset {
+            row
+        } else if row >= scroll_offset + window {
+            (row + 1 - window).min(self.max_scroll_offset())
+        } else {
+            scroll_offset
+        }
+    }
+
+    /// Returns the cell of the item at `index` when scrolled by `scroll_offset` rows, or `None` if
+    /// it isn't visible.
+    ///
+    /// Like [`auto_grid`](crate::auto_grid), cells without room are zero-size rects at the origin
+    /// of the area.
+    pub fn rect_for(&self, index: usize, scroll_offset: usize) -> Option<Rect> {
+        let cols = usize::from(self.cols);
+        let visible = self.visible_range(scroll_offset);
+        let row = if self.sticky_range().contains(&index) {
+            index / cols
+        } else if visible.contains(&index) {
+            usize::from(self.sticky_rows) + (index - visible.start) / cols
+        } else {
+            return None;
+        };
+
+        let col = index % cols;
+        let (x, width) = axis_track(
+            self.area.x,
+            self.area.width,
+            self.cols,
+            self.spacing,
+            col as u16,
+        );
+        let (y, height) = axis_track(
+            self.area.y,
+            self.area.height,
+            self.visible_rows,
+            self.spacing,
+            row as u16,
+        );
+
+        let cell = new_rect(x, y, width, height);
+        Some(if cell.is_empty() {
+            empty_cell(self.area)
+        } else {
+            cell
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn total_height_grows_with_n() {
+        let area = Rect::new(0, 0, 60, 20);
+        let mut previous = 0;
+        for n in [1, 3, 7, 13, 21, 31] {
+            let (cells, height) = auto_grid_scroll(area, n, 1, 4, 0);
+            assert_eq!(cells.len(), n);
+            assert!(height > previous, "n {n}: {height} <= {previous}");
+            assert_eq!(height, cells.last().unwrap().bottom() - area.y);
+            previous = height;
+        }
+    }
+
+    #[test]
+    fn rows_have_fixed_height() {
+        let area = Rect::new(2, 3, 30, 10);
+        let (cells, height) = auto_grid_scroll(area, 9, 0, 6, 0);
+
+        assert_eq!(height, 18);
+        assert!(cells.iter().all(|cell| cell.height == 6));
+        assert_eq!(cells[8], Rect::new(22, 15, 10, 6));
+    }
+
+    #[test]
+    fn scrolled_off_cells_are_clamped_to_the_top() {
+        let area = Rect::new(0, 5, 30, 10);
+        let (cells, _) = auto_grid_scroll(area, 9, 0, 6, 8);
+
+        // The first row is entirely above the area.
+        assert!(cells[..3].iter().all(|&cell| cell == Rect::new(0, 5, 0, 0)));
+        // The second row is cut at the top of the area.
+        assert_eq!(cells[3], Rect::new(0, 5, 10, 4));
+        assert_eq!(cells[6], Rect::new(0, 9, 10, 6));
+    }
+
+    #[test]
+    fn saturates_near_u16_max() {
+        let area = Rect::new(u16::MAX - 10, u16::MAX - 10, 10, 10);
+        let (cells, height) = auto_grid_scroll(area, 40, 3, 100, 0);
+        assert_eq!(cells.len(), 40);
+        assert_eq!(height, 615);
+        assert!(
+            cells
+                .iter()
+                .all(|cell| cell.y >= area.y && cell.x >= area.x)
+        );
+
+        let (_, height) = auto_grid_scroll(area, 10_000, 0, u16::MAX, u16::MAX);
+        assert_eq!(height, u16::MAX);
+    }
+
+    #[test]
+    fn offset_is_clamped_to_a_full_last_page() {
+        let area = Rect::new(0, 0, 100, 23);
+        for offset in [7, 8, 100, usize::MAX] {
+            // 10 columns and 10 rows, 4 of which fit.
+            let grid = ScrollableGrid::new(area, 95, 1, 4, offset);
+            assert_eq!(grid.total_rows(), 10);
+            assert_eq!(grid.visible_rows(), 4);
+            assert_eq!(grid.row_offset(), 6);
+            assert_eq!(grid.max_row_offset(), 6);
+
+            let indices: Vec<usize> = grid.cells().iter().map(|&(index, _)| index).collect();
+            assert_eq!(indices, (60..95).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn visible_cells_fill_the_area() {
+        let area = Rect::new(3, 4, 100, 23);
+        let grid = ScrollableGrid::new(area, 95, 1, 4, 2);
+
+        assert_eq!(grid.cells().len(), 40);
+        assert_eq!(grid.cells()[0], (20, Rect::new(3, 4, 10, 5)));
+        assert_eq!(grid.cells()[39], (59, Rect::new(94, 22, 9, 5)));
+    }
+
+    #[test]
+    fn everything_fits() {
+        let area = Rect::new(0, 0, 60, 60);
+        let grid = ScrollableGrid::new(area, 7, 1, 3, 5);
+
+        assert_eq!((grid.total_rows(), grid.visible_rows()), (3, 3));
+        assert_eq!(grid.row_offset(), 0);
+        let cells: Vec<Rect> = grid.cells().iter().map(|&(_, cell)| cell).collect();
+        assert_eq!(cells, crate::auto_grid(area, 7, 1));
+    }
+
+    #[test]
+    fn short_area_shows_one_row() {
+        let grid = ScrollableGrid::new(Rect::new(0, 0, 40, 2), 16, 0, 5, 1);
+        assert_eq!(grid.visible_rows(), 1);
+        assert_eq!(grid.cells()[0], (4, Rect::new(0, 0, 10, 2)));
+        assert_eq!(grid.cells().len(), 4);
+
+        let empty = ScrollableGrid::new(Rect::new(0, 0, 40, 2), 0, 0, 5, 1);
+        assert!(empty.cells().is_empty());
+        assert_eq!(empty.max_row_offset(), 0);
+    }
+
+    #[test]
+    fn virtual_auto_matches_auto_grid() {
+        for (area, spacing) in [
+            (Rect::new(0, 0, 100, 100), 1),
+            (Rect::new(3, 9, 77, 41), 2),
+            (Rect::new(0, 0, 5, 5), 3),
+            (Rect::new(2, 2, 0, 10), 0),
+        ] {
+            for n in 0..=50 {
+                let grid = VirtualGrid::new(area, n, spacing, VirtualLayout::Auto);
+                assert_eq!(grid.visible_range(3), 0..n);
+
+                let cells: Vec<Rect> = (0..n).filter_map(|i| grid.rect_for(i, 0)).collect();
+                assert_eq!(cells, crate::auto_grid(area, n, spacing), "{area:?}, n {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn virtual_columns_match_scrollable_grid() {
+        let area = Rect::new(3, 4, 100, 23);
+        let cols = dimensions(95).1;
+        let grid = VirtualGrid::new(area, 95, 1, VirtualLayout::Columns { cols, min_h: 4 });
+
+        assert_eq!(grid.content_height(), 10);
+        assert_eq!(grid.visible_rows(), 4);
+        for offset in [0, 2, 6, 9] {
+            let eager = ScrollableGrid::new(area, 95, 1, 4, offset);
+            let cells: Vec<(usize, Rect)> = grid
+                .visible_range(offset)
+                .map(|i| (i, grid.rect_for(i, offset).unwrap()))
+                .collect();
+            assert_eq!(cells, eager.cells(), "offset {offset}");
+        }
+    }
+
+    #[test]
+    fn virtual_range_is_clamped() {
+        let layout = VirtualLayout::MinCellSize {
+            min_w: 10,
+            min_h: 3,
+        };
+        let grid = VirtualGrid::new(Rect::new(0, 0, 80, 24), 50_001, 0, layout);
+
+        assert_eq!(grid.content_height(), 6251);
+        assert_eq!(grid.max_scroll_offset(), 6243);
+        assert_eq!(grid.visible_range(usize::MAX), 49_944..50_001);
+        assert_eq!(grid.rect_for(50_000, 9999), Some(Rect::new(0, 21, 10, 3)));
+        assert_eq!(grid.rect_for(50_001, 9999), None);
+
+        let empty = VirtualGrid::new(Rect::new(0, 0, 80, 24), 0, 0, layout);
+        assert_eq!(empty.visible_range(0), 0..0);
+        assert_eq!(empty.content_height(), 0);
+    }
+
+    #[test]
+    fn sticky_header_stays_put() {
+        let area = Rect::new(0, 0, 30, 20);
+        let layout = VirtualLayout::Columns { cols: 3, min_h: 2 };
+        let grid = VirtualGrid::new(area, 300, 0, layout).with_sticky_rows(1);
+
+        assert_eq!(grid.sticky_range(), 0..3);
+        assert_eq!(grid.visible_rows(), 10);
+        assert_eq!(grid.max_scroll_offset(), 90);
+
+        let header = |offset| -> Vec<Option<Rect>> {
+            (0..3).map(|index| grid.rect_for(index, offset)).collect()
+        };
+        let expected = vec![
+            Some(Rect::new(0, 0, 10, 2)),
+            Some(Rect::new(10, 0, 10, 2)),
+            Some(Rect::new(20, 0, 10, 2)),
+        ];
+        for offset in [0, 1, 50, 90, 1000] {
+            assert_eq!(header(offset), expected, "offset {offset}");
+        }
+
+        // The body scrolls below the header.
+        assert_eq!(grid.visible_range(0), 3..30);
+        assert_eq!(grid.visible_range(50), 153..180);
+        assert_eq!(grid.visible_range(1000), 273..300);
+        assert_eq!(grid.rect_for(153, 50), Some(Rect::new(0, 2, 10, 2)));
+        assert_eq!(grid.rect_for(299, 90), Some(Rect::new(20, 18, 10, 2)));
+        assert_eq!(grid.rect_for(3, 50), None);
+    }
+
+    #[test]
+    fn sticky_rows_leave_a_scrolling_row() {
+        let area = Rect::new(0, 0, 30, 6);
+        let layout = VirtualLayout::Columns { cols: 3, min_h: 2 };
+
+        let grid = VirtualGrid::new(area, 300, 0, layout).with_sticky_rows(5);
+        assert_eq!(grid.sticky_rows(), 2);
+        assert_eq!(grid.visible_range(0), 6..9);
+
+        // Everything fits, so every row can be sticky.
+        let grid = VirtualGrid::new(area, 9, 0, layout).with_sticky_rows(5);
+        assert_eq!(grid.sticky_rows(), 3);
+        assert_eq!(grid.visible_range(0), 9..9);
+        assert_eq!(grid.rect_for(8, 0), Some(Rect::new(20, 4, 10, 2)));
+    }
+
+    #[test]
+    fn scroll_to_moves_minimally() {
+        let area = Rect::new(0, 0, 30, 20);
+        let layout = VirtualLayout::Columns { cols: 3, min_h: 5 };
+        let grid = VirtualGrid::new(area, 59, 0, layout);
+        assert_eq!(grid.max_scroll_offset(), 16);
+
+        // Rows 5 to 8 are visible.
+        assert_eq!(grid.scroll_to(15, 5), 5);
+        assert_eq!(grid.scroll_to(26, 5), 5);
+        assert_eq!(grid.scroll_to(27, 5), 6);
+        assert_eq!(grid.scroll_to(14, 5), 4);
+        assert_eq!(grid.scroll_to(0, 5), 0);
+        assert_eq!(grid.scroll_to(58, 0), 16);
+        assert_eq!(grid.scroll_to(59, 100), 16);
+
+        for index in 0..59 {
+            for offset in [0, 7, 16, 100] {
+                let offset = grid.scroll_to(index, offset);
+                assert!(
+                    grid.visible_range(offset).contains(&index),
+                    "{index} {offset}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scroll_to_skips_sticky_rows() {
+        let area = Rect::new(0, 0, 30, 20);
+        let layout = VirtualLayout::Columns { cols: 3, min_h: 5 };
+        let grid = VirtualGrid::new(area, 59, 0, layout).with_sticky_rows(2);
+
+        // Rows 0 and 1 are sticky, and 2 scrolling rows show rows 7 and 8.
+        assert_eq!(grid.scroll_to(4, 5), 5);
+        assert_eq!(grid.scroll_to(21, 5), 5);
+        assert_eq!(grid.scroll_to(20, 5), 4);
+        assert_eq!(grid.scroll_to(6, 5), 0);
+        assert_eq!(grid.scroll_to(27, 5), 6);
+        assert_eq!(grid.scroll_to(58, 0), 16);
+
+        let fits = VirtualGrid::new(area, 9, 0, layout).with_sticky_rows(3);
+        assert_eq!(fits.scroll_to(8, 4), 0);
+    }
+
+    #[test]
+    fn clipped_to_the_top_half() {
+        let virtual_area = Rect::new(0, 0, 60, 60);
+        let viewport = Rect::new(0, 0, 60, 30);
+        let visible = auto_grid_clipped(virtual_area, viewport, 9, 0);
+
+        let cells = crate::auto_grid(virtual_area, 9, 0);
+        let expected: Vec<_> = [0, 1, 2]
+            .into_iter()
+            .map(|index| (index, cells[index]))
+            .chain([3, 4, 5].into_iter().map(|index| {
+                let cell = cells[index];
+                (index, Rect { height: 10, ..cell })
+            }))
+            .collect();
+        assert_eq!(visible, expected);
+        assert!(
+            visible
+                .iter()
+                .all(|(_, cell)| viewport.contains(cell.as_position()))
+        );
+    }
+
+    #[test]
+    fn clipped_outside_the_viewport() {
+        let virtual_area = Rect::new(0, 0, 60, 60);
+        assert!(auto_grid_clipped(virtual_area, Rect::new(0, 60, 60, 10), 9, 0).is_empty());
+        assert!(auto_grid_clipped(virtual_area, Rect::new(20, 0, 0, 10), 9, 0).is_empty());
+
+        // The viewport falls in the spacing between two rows.
+        assert!(auto_grid_clipped(virtual_area, Rect::new(0, 19, 60, 2), 9, 2).is_empty());
+    }
+
+    #[test]
+    fn fixed_density_pages() {
+        let area = Rect::new(2, 3, 50, 31);
+        let slots = crate::auto_grid(area, 12, 1);
+
+        for n in [4, 12, 40] {
+            let pages: Vec<Vec<Rect>> = (0..5)
+                .map(|page| auto_grid_fixed_density(area, 1, 12, n, page))
+                .collect();
+            let counts: Vec<usize> = pages.iter().map(Vec::len).collect();
+            let expected = match n {
+                4 => [4, 0, 0, 0, 0],
+                12 => [12, 0, 0, 0, 0],
+                _ => [12, 12, 12, 4, 0],
+            };
+            assert_eq!(counts, expected, "n {n}");
+            for page in &pages {
+                assert_eq!(page[..], slots[..page.len()]);
+            }
+        }
+
+        assert!(auto_grid_fixed_density(area, 1, 0, 10, 0).is_empty());
+        assert!(auto_grid_fixed_density(area, 1, 12, 10, usize::MAX).is_empty());
+    }
+}
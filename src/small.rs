@@ -0,0 +1,48 @@
+use ratatui::layout::Rect;
+use smallvec::SmallVec;
+
+use crate::fast::extend_fast;
+
+/// Number of cells [`auto_grid_small`] stores inline before spilling to the heap.
+///
+/// 16 cells cover grids of up to 4×4 while keeping the vector at a few hundred bytes on the stack.
+pub const INLINE_CELLS: usize = 16;
+
+/// Arranges `n` items in an automatic grid layout without allocating for small grids.
+///
+/// The cells are identical to [`auto_grid`](crate::auto_grid), but are stored inline for up to
+/// [`INLINE_CELLS`] items. Larger grids spill to the heap like a `Vec`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{auto_grid, auto_grid_small};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let cells = auto_grid_small(area, 4, 1);
+/// assert!(!cells.spilled());
+/// assert_eq!(cells.as_slice(), auto_grid(area, 4, 1));
+/// ```
+pub fn auto_grid_small(area: Rect, n: usize, spacing: u16) -> SmallVec<[Rect; INLINE_CELLS]> {
+    let mut out = SmallVec::new();
+    extend_fast::<SmallVec<[(u16, u16); INLINE_CELLS]>>(&mut out, area, n, spacing);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[test]
+    fn matches_auto_grid() {
+        let area = Rect::new(2, 3, 97, 61);
+        for n in 0..=40 {
+            let cells = auto_grid_small(area, n, 1);
+            assert_eq!(cells.as_slice(), auto_grid(area, n, 1));
+            assert_eq!(cells.spilled(), n > INLINE_CELLS);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+use crate::fast::split_axis;
+use crate::{collapse_empty, dimensions, empty_cell, new_rect};
+
+/// The number of grid slots covered by an item in [`auto_grid_spans`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellSpan {
+    /// Number of columns covered by the item.
+    pub col_span: u16,
+    /// Number of rows covered by the item.
+    pub row_span: u16,
+}
+
+impl CellSpan {
+    /// Creates a span covering `col_span` columns and `row_span` rows.
+    pub const fn new(col_span: u16, row_span: u16) -> Self {
+        Self { col_span, row_span }
+    }
+}
+
+impl Default for CellSpan {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+/// Arranges items spanning several rows and/or columns in an automatic grid layout.
+///
+/// The column count is chosen with the same square root approach as
+/// [`auto_grid`](crate::auto_grid), applied to the total number of slots covered by the items.
+/// Items are then placed left-to-right, top-to-bottom, each one at the first position after the
+/// previous item where all of its slots are free, like the CSS grid auto-placement algorithm.
+/// Spans of 0 are treated as 1 and column spans larger than the column count are clamped, so items
+/// can never overlap.
+///
+/// The grid has at most `u16::MAX` rows. Items placed past that, and items whose first slot has no
+/// room, come back as zero-size rects at the origin of `area`.
+///
+/// # Returns
+///
+/// One rect per item, in the same order as `items`. Each rect covers all the slots of the item,
+/// including the spacing between them.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{CellSpan, auto_grid_spans};
+///
+/// let area = Rect::new(0, 0, 100, 100);
+/// let items = [CellSpan::new(2, 1), CellSpan::new(1, 1), CellSpan::new(1, 1)];
+/// let cells = auto_grid_spans(area, &items, 0);
+///
+/// assert_eq!(cells[0], Rect::new(0, 0, 100, 50));
+/// assert_eq!(cells[1], Rect::new(0, 50, 50, 50));
+/// assert_eq!(cells[2], Rect::new(50, 50, 50, 50));
+/// ```
+pub fn auto_grid_spans(area: Rect, items: &[CellSpan], spacing: u16) -> Vec<Rect> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+
+    let slots: usize = items
+        .iter()
+        .map(|span| usize::from(span.col_span.max(1)) * usize::from(span.row_span.max(1)))
+        .sum();
+    let (_, cols) = dimensions(slots);
+
+    let placements = place(items, cols);
+    let rows = placements
+        .iter()
+        .map(|&(r, _, _, row_span)| r + row_span)
+        .max()
+        .unwrap_or(0);
+
+    let rows = rows.min(usize::from(u16::MAX));
+    let columns: Vec<_> = split_axis(area.x, area.width, cols, spacing).collect();
+    let tracks: Vec<_> = split_axis(area.y, area.height, rows as u16, spacing).collect();
+    let slot = |r: usize, c: usize| {
+        let ((x, width), (y, height)) = (columns[c], tracks[r]);
+        new_rect(x, y, width, height)
+    };
+
+    let mut out: Vec<Rect> = placements
+        .into_iter()
+        .map(|(r, c, col_span, row_span)| {
+            if r + row_span > rows || slot(r, c).is_empty() {
+                return empty_cell(area);
+            }
+            slot(r, c).union(slot(r + row_span - 1, c + col_span - 1))
+        })
+        .collect();
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Places the items in a grid of `cols` columns, returning `(row, col, col_span, row_span)` for
+/// each item.
+fn place(items: &[CellSpan], cols: u16) -> Vec<(usize, usize, usize, usize)> {
+    let cols = usize::from(cols);
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let (mut row, mut col) = (0, 0);
+
+    items
+        .iter()
+        .map(|span| {
+            let col_span = usize::from(span.col_span.clamp(1, cols as u16));
+            let row_span = usize::from(span.row_span.max(1));
+
+            loop {
+                if col + col_span > cols {
+                    row += 1;
+                    col = 0;
+                    continue;
+                }
+
+                let free = (row..row + row_span).all(|r| {
+                    occupied
+                        .get(r)
+                        .is_none_or(|cells| cells[col..col + col_span].iter().all(|&o| !o))
+                });
+                if free {
+                    break;
+                }
+                col += 1;
+            }
+
+            if occupied.len() < row + row_span {
+                occupied.resize(row + row_span, vec![false; cols]);
+            }
+            for cells in &mut occupied[row..row + row_span] {
+                cells[col..col + col_span].fill(true);
+            }
+
+            let placement = (row, col, col_span, row_span);
+            col += col_span;
+            placement
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_slots_covered_by_earlier_spans() {
+        let area = Rect::new(0, 0, 90, 90);
+        // 9 slots: 3 columns.
+        let items = [
+            CellSpan::new(1, 2),
+            CellSpan::new(2, 1),
+            CellSpan::new(1, 1),
+            CellSpan::new(1, 1),
+            CellSpan::new(1, 1),
+            CellSpan::new(1, 1),
+        ];
+        let result = auto_grid_spans(area, &items, 0);
+
+        assert_eq!(result[0], Rect::new(0, 0, 30, 60));
+        assert_eq!(result[1], Rect::new(30, 0, 60, 30));
+        // (1, 0) is covered by the first item.
+        assert_eq!(result[2], Rect::new(30, 30, 30, 30));
+        assert_eq!(result[3], Rect::new(60, 30, 30, 30));
+        assert_eq!(result[4], Rect::new(0, 60, 30, 30));
+        assert_eq!(result[5], Rect::new(30, 60, 30, 30));
+    }
+
+    #[test]
+    fn spans_include_spacing() {
+        let area = Rect::new(0, 0, 101, 101);
+        let items = [
+            CellSpan::new(2, 2),
+            CellSpan::default(),
+            CellSpan::default(),
+        ];
+        let result = auto_grid_spans(area, &items, 1);
+
+        let single = result[1];
+        assert_eq!(result[0].width, 2 * single.width + 1);
+        assert_eq!(result[0].height, 2 * single.height + 1);
+    }
+
+    #[test]
+    fn clamps_wide_spans() {
+        let area = Rect::new(0, 0, 100, 100);
+        let items = [CellSpan::new(1, 1), CellSpan::new(10, 1)];
+        let result = auto_grid_spans(area, &items, 0);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].x, area.x);
+        assert_eq!(result[1].width, area.width);
+    }
+
+    #[test]
+    fn never_overlaps() {
+        let area = Rect::new(0, 0, 120, 120);
+        let items: Vec<CellSpan> = (0..20)
+            .map(|i| CellSpan::new(i % 3 + 1, (i * 7) % 2 + 1))
+            .collect();
+        let result = auto_grid_spans(area, &items, 1);
+
+        assert_eq!(result.len(), items.len());
+        for (i, a) in result.iter().enumerate() {
+            for b in &result[i + 1..] {
+                assert!(!a.intersects(*b), "{a:?} overlaps {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn spans_past_the_last_row_are_empty() {
+        let area = Rect::new(0, 0, 100, 100);
+        let items = [CellSpan::new(1, u16::MAX), CellSpan::new(u16::MAX, 1)];
+        let result = auto_grid_spans(area, &items, 0);
+
+        assert_eq!(result[0], Rect::new(0, 0, 1, 100));
+        assert_eq!(result[1], Rect::ZERO);
+    }
+
+    #[test]
+    fn empty_area_collapses_to_the_origin() {
+        let area = Rect::new(5, 5, 0, 10);
+        let items = [
+            CellSpan::new(2, 1),
+            CellSpan::default(),
+            CellSpan::default(),
+        ];
+        assert_eq!(auto_grid_spans(area, &items, 1), [Rect::new(5, 5, 0, 0); 3]);
+    }
+}
@@ -0,0 +1,517 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui::layout::Rect;
+
+use crate::{collapse_empty, dimensions, empty_cell, fast::split_axis, new_rect};
+
+/// How far from the spacing between two tracks, in cells, a drag can start.
+const DRAG_TOLERANCE: u16 = 1;
+
+/// The interaction state of a resizable grid laid out with [`auto_grid_resizable`].
+///
+/// The state holds width overrides for the columns and height overrides for the rows, which are
+/// changed by dragging the separators between them with [`GridState::begin_drag`],
+/// [`GridState::drag_to`] and [`GridState::end_drag`]. It also remembers the tracks of the last
+/// layout, so separators are found where they were last drawn.
+///
+/// The state also holds a selection of items for batch operations, changed with
+/// [`GridState::toggle`], [`GridState::select_range`] and [`GridState::clear_selection`]. Only the
+/// items of the last layout can be selected.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::{GridState, auto_grid_resizable};
+///
+/// let area = Rect::new(0, 0, 100, 50);
+/// let mut state = GridState::new();
+/// auto_grid_resizable(area, 4, 2, &mut state);
+///
+/// // Grab the separator between the two columns and move it 10 cells to the right.
+/// assert!(state.begin_drag(50, 10));
+/// state.drag_to(60, 10);
+/// state.end_drag();
+///
+/// let cells = auto_grid_resizable(area, 4, 2, &mut state);
+/// assert_eq!(cells[0], Rect::new(0, 0, 59, 24));
+/// assert_eq!(cells[1], Rect::new(61, 0, 39, 24));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridState {
+    column_widths: Vec<Option<u16>>,
+    row_heights: Vec<Option<u16>>,
+    min_width: u16,
+    min_height: u16,
+    columns: Vec<(u16, u16)>,
+    rows: Vec<(u16, u16)>,
+    drag: Drag,
+    len: usize,
+    selected: BTreeSet<usize>,
+}
+
+/// The separators being dragged on each axis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Drag {
+    column: Option<Separator>,
+    row: Option<Separator>,
+}
+
+/// A separator grabbed between the tracks `index` and `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Separator {
+    index: usize,
+    /// Where the drag started on this axis.
+    origin: u16,
+    /// The sizes of the two tracks when the drag started.
+    sizes: (u16, u16),
+}
+
+impl Default for GridState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridState {
+    /// Creates a state without overrides, where tracks can be shrunk down to 1 cell.
+    pub fn new() -> Self {
+        Self::with_min_cell_size(1, 1)
+    }
+
+    /// Creates a state without overrides, where dragging can't make columns narrower than
+    /// `min_width` or rows shorter than `min_height`.
+    pub fn with_min_cell_size(min_width: u16, min_height: u16) -> Self {
+        Self {
+            column_widths: Vec::new(),
+            row_heights: Vec::new(),
+            min_width,
+            min_height,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            drag: Drag::default(),
+            len: 0,
+            selected: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the width override of each column of the last layout, if any.
+    pub fn column_widths(&self) -> &[Option<u16>] {
+        &self.column_widths
+    }
+
+    /// Returns the height override of each row of the last layout, if any.
+    pub fn row_heights(&self) -> &[Option<u16>] {
+        &self.row_heights
+    }
+
+    /// Starts dragging the separator at `(x, y)`, if any.
+    ///
+    /// A separator is the spacing between two columns (or rows) of the last layout, widened by one
+    /// cell on each side so that it can be grabbed even without spacing. A position where a column
+    /// and a row separator cross drags both.
+    ///
+    /// # Returns
+    ///
+    /// Whether a separator was grabbed.
+    pub fn begin_drag(&mut self, x: u16, y: u16) -> bool {
+        let within = |tracks: &[(u16, u16)], position: u16| match (tracks.first(), tracks.last()) {
+            (Some(&(start, _)), Some(&(end, size))) => {
+                (start..end.saturating_add(size)).contains(&position)
+            }
+            _ => false,
+        };
+
+        self.drag = Drag {
+            column: within(&self.rows, y)
+                .then(|| grab(&self.columns, x))
+                .flatten(),
+            row: within(&self.columns, x)
+                .then(|| grab(&self.rows, y))
+                .flatten(),
+        };
+        self.drag.column.is_some() || self.drag.row.is_some()
+    }
+
+    /// Moves the grabbed separators to `(x, y)`.
+    ///
+    /// The two tracks on each side of a separator trade size, so the other tracks don't move. They
+    /// are kept above the minimum cell size.
+    ///
+    /// # Returns
+    ///
+    /// Whether a drag is in progress.
+    pub fn drag_to(&mut self, x: u16, y: u16) -> bool {
+        if let Some(separator) = self.drag.column {
+            separator.apply(x, self.min_width, &mut self.column_widths);
+        }
+        if let Some(separator) = self.drag.row {
+            separator.apply(y, self.min_height, &mut self.row_heights);
+        }
+        self.drag != Drag::default()
+    }
+
+    /// Ends the current drag, keeping the overrides it set.
+    pub fn end_drag(&mut self) {
+        self.drag = Drag::default();
+    }
+
+    /// Clears every override and the current drag, going back to an even grid.
+    pub fn reset(&mut self) {
+        self.column_widths.clear();
+        self.row_heights.clear();
+        self.end_drag();
+    }
+
+    /// Selects item `i` if it isn't, and deselects it otherwise.
+    ///
+    /// # Returns
+    ///
+    /// Whether the item is selected afterwards, which is never the case for an index past the
+    /// items of the last layout.
+    pub fn toggle(&mut self, i: usize) -> bool {
+        if i >= self.len {
+            return false;
+        }
+        if !self.selected.remove(&i) {
+            self.selected.insert(i);
+        }
+        self.is_selected(i)
+    }
+
+    /// Adds the items from `a` to `b` in row-major order to the selection, in either direction.
+    ///
+    /// Both ends are included. The range is clamped to the items of the last layout, so it stops
+    /// at the last cell of a partial last row.
+    pub fn select_range(&mut self, a: usize, b: usize) {
+        let (start, end) = (a.min(b), a.max(b).min(self.len.saturating_sub(1)));
+        if start < self.len {
+            self.selected.extend(start..=end);
+        }
+    }
+
+    /// Deselects every item.
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Returns whether item `i` is selected.
+    pub fn is_selected(&self, i: usize) -> bool {
+        self.selected.contains(&i)
+    }
+
+    /// Returns the indices of the selected items, in increasing order.
+    pub fn selected_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+}
+
+impl Separator {
+    /// Resizes the two tracks of this separator for a drag to `position`.
+    fn apply(self, position: u16, min: u16, overrides: &mut [Option<u16>]) {
+        let (before, after) = self.sizes;
+        let total = before + after;
+        if total < min.saturating_mul(2) || self.index + 1 >= overrides.len() {
+            return;
+        }
+
+        let delta = i32::from(position) - i32::from(self.origin);
+        let before =
+            (i32::from(before) + delta).clamp(i32::from(min), i32::from(total - min)) as u16;
+        overrides[self.index] = Some(before);
+        overrides[self.index + 1] = Some(total - before);
+    }
+}
+
+/// Finds the separator of `tracks` at `position`.
+fn grab(tracks: &[(u16, u16)], position: u16) -> Option<Separator> {
+    tracks.windows(2).enumerate().find_map(|(index, pair)| {
+        let [(start, size), (next, next_size)] = [pair[0], pair[1]];
+        let end = start + size;
+        let band = end.saturating_sub(DRAG_TOLERANCE)..next.saturating_add(DRAG_TOLERANCE);
+        band.contains(&position).then_some(Separator {
+            index,
+            origin: position,
+            sizes: (size, next_size),
+        })
+    })
+}
+
+/// Arranges `n` items in an automatic grid layout whose tracks can be resized with a [`GridState`].
+///
+/// Without overrides, this is the same as [`auto_grid`](crate::auto_grid). Columns and rows with
+/// an override keep their size, as long as there is room for it and for the minimum cell size of
+/// every other track, and the tracks without one share what's left evenly. The cells always stay
+/// within `area`, and when it is too small for the minimum cell size the overrides are ignored.
+///
+/// The tracks of this layout are recorded in `state` to find the separators of the next drag, and
+/// the selected items past the `n` items of this layout are deselected.
+pub fn auto_grid_resizable(area: Rect, n: usize, spacing: u16, state: &mut GridState) -> Vec<Rect> {
+    state.len = n;
+    state.selected.split_off(&n);
+    if n == 0 {
+        state.columns.clear();
+        state.rows.clear();
+        return Vec::new();
+    }
+
+    let (rows, cols) = dimensions(n);
+    state.column_widths.resize(cols.into(), None);
+    state.row_heights.resize(rows.into(), None);
+    state.columns = resized_axis(
+        area.x,
+        area.width,
+        spacing,
+        &state.column_widths,
+        state.min_width,
+    );
+    state.rows = resized_axis(
+        area.y,
+        area.height,
+        spacing,
+        &state.row_heights,
+        state.min_height,
+    );
+
+    if area.is_empty() {
+        return vec![empty_cell(area); n];
+    }
+
+    let mut out: Vec<Rect> = state
+        .rows
+        .iter()
+        .flat_map(|&(y, height)| {
+            state
+                .columns
+                .iter()
+                .map(move |&(x, width)| new_rect(x, y, width, height))
+        })
+        .take(n)
+        .collect();
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Splits `length` cells starting at `start` into one track per override, separated by
+/// `spacing`, yielding the `(position, size)` of each track.
+fn resized_axis(
+    start: u16,
+    length: u16,
+    spacing: u16,
+    overrides: &[Option<u16>],
+    min: u16,
+) -> Vec<(u16, u16)> {
+    let tracks = overrides.len() as u32;
+    let available = u32::from(length).saturating_sub(u32::from(spacing) * tracks.saturating_sub(1));
+    let min = u32::from(min);
+    if overrides.iter().all(Option::is_none) || available < min * tracks {
+        return split_axis(start, length, tracks as u16, spacing).collect();
+    }
+
+    // Every override is clamped so that the tracks after it still get their minimum size.
+    let free = overrides.iter().filter(|size| size.is_none()).count() as u32;
+    let mut budget = available - min * free;
+    let mut overridden = tracks - free;
+    let mut sizes: Vec<Option<u32>> = overrides
+        .iter()
+        .map(|size| {
+            let size = size.map(u32::from)?;
+            overridden -= 1;
+            let size = size.clamp(min, budget - min * overridden);
+            budget -= size;
+            Some(size)
+        })
+        .collect();
+
+    // What's left is shared by the tracks without an override, the first ones getting the extra
+    // cells, or goes to the last track if they all have one.
+    let remaining = available - sizes.iter().flatten().sum::<u32>();
+    match remaining.checked_div(free) {
+        Some(size) => {
+            let extra = remaining % free;
+            for (i, slot) in sizes.iter_mut().filter(|size| size.is_none()).enumerate() {
+                *slot = Some(size + u32::from((i as u32) < extra));
+            }
+        }
+        None => {
+            if let Some(Some(last)) = sizes.last_mut() {
+                *last += remaining;
+            }
+        }
+    }
+
+    let mut position = u32::from(start);
+    sizes
+        .into_iter()
+        .flatten()
+        .map(|size| {
+            let track = (position as u16, size as u16);
+            position += size + u32::from(spacing);
+            track
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auto_grid;
+
+    #[test]
+    fn matches_auto_grid_without_overrides() {
+        let mut state = GridState::new();
+        for (area, n, spacing) in [
+            (Rect::new(0, 0, 100, 100), 7, 1),
+            (Rect::new(3, 9, 77, 41), 23, 2),
+            (Rect::new(0, 0, 5, 5), 9, 3),
+            (Rect::new(2, 2, 0, 10), 4, 0),
+        ] {
+            let result = auto_grid_resizable(area, n, spacing, &mut state);
+            assert_eq!(result, auto_grid(area, n, spacing));
+        }
+    }
+
+    #[test]
+    fn drag_column_separator() {
+        let area = Rect::new(0, 0, 100, 50);
+        let mut state = GridState::new();
+        auto_grid_resizable(area, 4, 2, &mut state);
+
+        assert!(state.begin_drag(50, 10));
+        assert!(state.drag_to(40, 40));
+        state.end_drag();
+        assert!(!state.drag_to(0, 0));
+
+        let cells = auto_grid_resizable(area, 4, 2, &mut state);
+        assert_eq!(state.column_widths(), [Some(39), Some(59)]);
+        assert_eq!(state.row_heights(), [None, None]);
+        assert_eq!(cells[0], Rect::new(0, 0, 39, 24));
+        assert_eq!(cells[3], Rect::new(41, 26, 59, 24));
+    }
+
+    #[test]
+    fn drag_crossing_moves_both_axes() {
+        let area = Rect::new(0, 0, 100, 50);
+        let mut state = GridState::new();
+        auto_grid_resizable(area, 4, 0, &mut state);
+
+        assert!(state.begin_drag(49, 25));
+        state.drag_to(59, 20);
+
+        let cells = auto_grid_resizable(area, 4, 0, &mut state);
+        assert_eq!(cells[0], Rect::new(0, 0, 60, 20));
+        assert_eq!(cells[3], Rect::new(60, 20, 40, 30));
+    }
+
+    #[test]
+    fn grab_tolerance() {
+        let area = Rect::new(0, 0, 100, 50);
+        let mut state = GridState::new();
+        auto_grid_resizable(area, 2, 2, &mut state);
+
+        // The spacing is at 49 and 50.
+        assert!(!state.begin_drag(47, 10));
+        assert!(state.begin_drag(48, 10));
+        assert!(state.begin_drag(51, 10));
+        assert!(!state.begin_drag(52, 10));
+        // Outside of the grid.
+        assert!(!state.begin_drag(50, 50));
+    }
+
+    #[test]
+    fn drag_is_clamped_to_min_size() {
+        let area = Rect::new(0, 0, 90, 30);
+        let mut state = GridState::with_min_cell_size(10, 5);
+        auto_grid_resizable(area, 9, 0, &mut state);
+
+        assert!(state.begin_drag(30, 0));
+        state.drag_to(u16::MAX, 0);
+        let cells = auto_grid_resizable(area, 9, 0, &mut state);
+        assert_eq!(cells[1].width, 10);
+        assert_eq!(cells[0].width, 50);
+        assert_eq!(cells[2], Rect::new(60, 0, 30, 10));
+
+        state.drag_to(0, 0);
+        let cells = auto_grid_resizable(area, 9, 0, &mut state);
+        assert_eq!(cells[0].width, 10);
+        assert_eq!(cells[1].width, 50);
+    }
+
+    #[test]
+    fn overrides_stay_within_a_smaller_area() {
+        let mut state = GridState::new();
+        auto_grid_resizable(Rect::new(0, 0, 100, 100), 9, 1, &mut state);
+        state.begin_drag(33, 50);
+        state.drag_to(90, 50);
+
+        for width in [100, 60, 20, 3, 0] {
+            let area = Rect::new(5, 5, width, 40);
+            let cells = auto_grid_resizable(area, 9, 1, &mut state);
+            assert_eq!(cells.len(), 9);
+            for cell in cells {
+                assert!(
+                    cell.x >= area.x && cell.right() <= area.right(),
+                    "{cell:?} in {area:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reset_clears_overrides() {
+        let area = Rect::new(0, 0, 100, 50);
+        let mut state = GridState::new();
+        auto_grid_resizable(area, 4, 2, &mut state);
+        state.begin_drag(50, 10);
+        state.drag_to(60, 10);
+
+        state.reset();
+        assert!(!state.drag_to(70, 10));
+        assert_eq!(
+            auto_grid_resizable(area, 4, 2, &mut state),
+            auto_grid(area, 4, 2)
+        );
+    }
+
+    #[test]
+    fn toggle_selection() {
+        let area = Rect::new(0, 0, 100, 50);
+        let mut state = GridState::new();
+        assert!(!state.toggle(0));
+
+        auto_grid_resizable(area, 5, 1, &mut state);
+        assert!(state.toggle(3));
+        assert!(state.toggle(1));
+        assert!(!state.toggle(5));
+        assert!(state.is_selected(3) && !state.is_selected(5));
+        assert_eq!(state.selected_indices().collect::<Vec<_>>(), [1, 3]);
+
+        assert!(!state.toggle(3));
+        assert_eq!(state.selected_indices().collect::<Vec<_>>(), [1]);
+        state.clear_selection();
+        assert_eq!(state.selected_indices().count(), 0);
+    }
+
+    #[test]
+    fn range_selection_is_clamped_to_the_items() {
+        let area = Rect::new(0, 0, 90, 30);
+        let mut state = GridState::new();
+        // 3 columns, with 1 item on the last row.
+        auto_grid_resizable(area, 7, 0, &mut state);
+
+        state.select_range(8, 5);
+        assert_eq!(state.selected_indices().collect::<Vec<_>>(), [5, 6]);
+        state.select_range(0, 1);
+        state.select_range(7, 20);
+        assert_eq!(state.selected_indices().collect::<Vec<_>>(), [0, 1, 5, 6]);
+
+        // Fewer items drop the selection past them.
+        auto_grid_resizable(area, 4, 0, &mut state);
+        assert_eq!(state.selected_indices().collect::<Vec<_>>(), [0, 1]);
+        auto_grid_resizable(area, 0, 0, &mut state);
+        assert!(!state.is_selected(0));
+    }
+}
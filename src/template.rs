@@ -0,0 +1,333 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use ratatui::layout::{Constraint, Rect};
+
+use crate::split_constraints;
+
+/// The size of a row or column in a [`TrackTemplate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Track {
+    /// A share of the remaining space, written `Nfr` (or `*` for `1fr`).
+    Fr(u16),
+    /// A fixed length, written `N`.
+    Length(u16),
+    /// A percentage of the area, written `N%`.
+    Percent(u16),
+}
+
+impl Track {
+    fn constraint(self) -> Constraint {
+        match self {
+            Self::Fr(weight) => Constraint::Fill(weight),
+            Self::Length(length) => Constraint::Length(length),
+            Self::Percent(pct) => Constraint::Percentage(pct),
+        }
+    }
+}
+
+/// The shape of a grid parsed from a compact template string.
+///
+/// A template is made of columns and rows separated by `/`, each being a whitespace-separated list
+/// of tracks:
+///
+/// - `Nfr` is a share of the remaining space, and `*` is shorthand for `1fr`
+/// - `N` is a fixed length
+/// - `N%` is a percentage of the area
+///
+/// As a shorthand, a side made of a single unitless number `N` means `N` equal tracks, so
+/// `"1fr 2fr 1fr / 3"` has three columns with relative widths 1:2:1 and three equal rows.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_auto_grid::{Track, TrackTemplate};
+///
+/// let template: TrackTemplate = "20 1fr 30% / * *".parse().unwrap();
+/// assert_eq!(
+///     template.columns(),
+///     [Track::Length(20), Track::Fr(1), Track::Percent(30)]
+/// );
+/// assert_eq!(template.rows(), [Track::Fr(1), Track::Fr(1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrackTemplate {
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+}
+
+impl TrackTemplate {
+    /// Parses a template string.
+    pub fn parse(s: &str) -> Result<Self, ParseTemplateError> {
+        let Some(separator) = s.find('/') else {
+            return Err(ParseTemplateError::new(
+                s.len(),
+                ParseTemplateErrorKind::MissingSeparator,
+            ));
+        };
+        if let Some(extra) = s[separator + 1..].find('/') {
+            return Err(ParseTemplateError::new(
+                separator + 1 + extra,
+                ParseTemplateErrorKind::ExtraSeparator,
+            ));
+        }
+
+        let columns = parse_tracks(&s[..separator], 0)?;
+        let rows = parse_tracks(&s[separator + 1..], separator + 1)?;
+        Ok(Self { columns, rows })
+    }
+
+    /// Returns the column tracks.
+    pub fn columns(&self) -> &[Track] {
+        &self.columns
+    }
+
+    /// Returns the row tracks.
+    pub fn rows(&self) -> &[Track] {
+        &self.rows
+    }
+
+    /// Splits `area` into one cell per row and column, returned in row-major order.
+    pub fn split(&self, area: Rect, spacing: u16) -> Vec<Rect> {
+        let row_constraints: Vec<Constraint> = self.rows.iter().map(|t| t.constraint()).collect();
+        let col_constraints: Vec<Constraint> =
+            self.columns.iter().map(|t| t.constraint()).collect();
+        split_constraints(area, &row_constraints, &col_constraints, spacing, spacing)
+    }
+}
+
+impl FromStr for TrackTemplate {
+    type Err = ParseTemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parses the tracks of one side of a template, `offset` being the position of `s` in the whole
+/// template.
+fn parse_tracks(s: &str, offset: usize) -> Result<Vec<Track>, ParseTemplateError> {
+    let mut tokens: Vec<(usize, &str)> = Vec::new();
+    let mut start = None;
+    for (i, ch) in s.char_indices().chain(Some((s.len(), ' '))) {
+        match (start, ch.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(begin), true) => {
+                tokens.push((offset + begin, &s[begin..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let invalid =
+        |position| ParseTemplateError::new(position, ParseTemplateErrorKind::InvalidTrack);
+    match tokens.as_slice() {
+        [] => Err(ParseTemplateError::new(
+            offset,
+            ParseTemplateErrorKind::EmptyTracks,
+        )),
+        &[(position, token)] if token.bytes().all(|b| b.is_ascii_digit()) => {
+            let count: u16 = token.parse().map_err(|_| invalid(position))?;
+            if count == 0 {
+                return Err(ParseTemplateError::new(
+                    position,
+                    ParseTemplateErrorKind::EmptyTracks,
+                ));
+            }
+            Ok(vec![Track::Fr(1); usize::from(count)])
+        }
+        tokens => tokens
+            .iter()
+            .map(|&(position, token)| parse_track(token).ok_or_else(|| invalid(position)))
+            .collect(),
+    }
+}
+
+fn parse_track(token: &str) -> Option<Track> {
+    let number = |digits: &str| {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    };
+
+    if token == "*" {
+        Some(Track::Fr(1))
+    } else if let Some(weight) = token.strip_suffix("fr") {
+        number(weight).map(Track::Fr)
+    } else if let Some(pct) = token.strip_suffix('%') {
+        number(pct).map(Track::Percent)
+    } else {
+        number(token).map(Track::Length)
+    }
+}
+
+/// Arranges cells according to a compact template string.
+///
+/// See [`TrackTemplate`] for the syntax. Returns the cells in row-major order, or an error if the
+/// template is malformed.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::auto_grid_template;
+///
+/// let area = Rect::new(0, 0, 100, 90);
+/// let cells = auto_grid_template(area, "1fr 2fr 1fr / 3", 0).unwrap();
+/// assert_eq!(cells.len(), 9);
+/// assert_eq!(cells[1].width, 50);
+/// ```
+pub fn auto_grid_template(
+    area: Rect,
+    template: &str,
+    spacing: u16,
+) -> Result<Vec<Rect>, ParseTemplateError> {
+    Ok(TrackTemplate::parse(template)?.split(area, spacing))
+}
+
+/// An error returned when a [`TrackTemplate`] string is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseTemplateError {
+    position: usize,
+    kind: ParseTemplateErrorKind,
+}
+
+impl ParseTemplateError {
+    fn new(position: usize, kind: ParseTemplateErrorKind) -> Self {
+        Self { position, kind }
+    }
+
+    /// Returns the byte offset in the template where the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the kind of error.
+    pub fn kind(&self) -> ParseTemplateErrorKind {
+        self.kind
+    }
+}
+
+/// The kind of a [`ParseTemplateError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTemplateErrorKind {
+    /// The `/` between columns and rows is missing.
+    MissingSeparator,
+    /// There is more than one `/`.
+    ExtraSeparator,
+    /// Columns or rows have no tracks.
+    EmptyTracks,
+    /// A track is not a valid size.
+    InvalidTrack,
+}
+
+impl fmt::Display for ParseTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.kind {
+            ParseTemplateErrorKind::MissingSeparator => "missing `/` between columns and rows",
+            ParseTemplateErrorKind::ExtraSeparator => "unexpected `/`",
+            ParseTemplateErrorKind::EmptyTracks => "expected at least one track",
+            ParseTemplateErrorKind::InvalidTrack => "invalid track size",
+        };
+        write!(f, "{reason} at position {}", self.position)
+    }
+}
+
+impl core::error::Error for ParseTemplateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tracks() {
+        let template = TrackTemplate::parse("1fr 2fr 1fr / 3").unwrap();
+        assert_eq!(
+            template.columns(),
+            [Track::Fr(1), Track::Fr(2), Track::Fr(1)]
+        );
+        assert_eq!(template.rows(), [Track::Fr(1); 3]);
+
+        let template = TrackTemplate::parse("* * / * *").unwrap();
+        assert_eq!(template.columns(), [Track::Fr(1); 2]);
+        assert_eq!(template.rows(), [Track::Fr(1); 2]);
+
+        let template = TrackTemplate::parse("20 30% / 5 1fr").unwrap();
+        assert_eq!(template.columns(), [Track::Length(20), Track::Percent(30)]);
+        assert_eq!(template.rows(), [Track::Length(5), Track::Fr(1)]);
+    }
+
+    #[test]
+    fn splits_in_row_major_order() {
+        let area = Rect::new(0, 0, 100, 90);
+        let result = auto_grid_template(area, "1fr 2fr 1fr / 3", 0).unwrap();
+
+        assert_eq!(result.len(), 9);
+        assert_eq!(result[0], Rect::new(0, 0, 25, 30));
+        assert_eq!(result[1], Rect::new(25, 0, 50, 30));
+        assert_eq!(result[2], Rect::new(75, 0, 25, 30));
+        assert_eq!(result[3], Rect::new(0, 30, 25, 30));
+        assert_eq!(result[8], Rect::new(75, 60, 25, 30));
+    }
+
+    #[test]
+    fn fixed_and_percentage_tracks() {
+        let area = Rect::new(0, 0, 100, 10);
+        let result = auto_grid_template(area, "20 30% * / 1", 0).unwrap();
+
+        assert_eq!(result[0].width, 20);
+        assert_eq!(result[1].width, 30);
+        assert_eq!(result[2].width, 50);
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let error = |s| TrackTemplate::parse(s).unwrap_err();
+
+        assert_eq!(
+            error("1fr 2fr"),
+            ParseTemplateError::new(7, ParseTemplateErrorKind::MissingSeparator)
+        );
+        assert_eq!(
+            error("* / * / *"),
+            ParseTemplateError::new(6, ParseTemplateErrorKind::ExtraSeparator)
+        );
+        assert_eq!(
+            error("  / *"),
+            ParseTemplateError::new(0, ParseTemplateErrorKind::EmptyTracks)
+        );
+        assert_eq!(
+            error("* / 0"),
+            ParseTemplateError::new(4, ParseTemplateErrorKind::EmptyTracks)
+        );
+        assert_eq!(
+            error("1fr xfr / 2"),
+            ParseTemplateError::new(4, ParseTemplateErrorKind::InvalidTrack)
+        );
+        assert_eq!(error("* 99999 / 1").position(), 2);
+    }
+
+    #[test]
+    fn malformed_templates_never_panic() {
+        for s in [
+            "",
+            "/",
+            "//",
+            "fr / fr",
+            "% / %",
+            "-1 / 2",
+            "1.5fr / 2",
+            "é / ü",
+            "*/*",
+            "* *",
+        ] {
+            let _ = TrackTemplate::parse(s);
+        }
+        assert!(TrackTemplate::parse("*/*").is_ok());
+    }
+}
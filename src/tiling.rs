@@ -0,0 +1,477 @@
+use alloc::vec::Vec;
+
+use ratatui::layout::{Direction, Rect};
+
+use crate::{collapse_empty, empty_cell, rows};
+
+/// Arranges `n` items in golden-ratio tiles, like the tiling of some window managers.
+///
+/// The first item gets about 61.8% of the area, and the rest is divided again the same way for the
+/// next items, alternating between a vertical cut (side by side) and a horizontal cut (one above
+/// the other), starting with a vertical one. The last item gets whatever is left, so the tiles
+/// cover the whole area apart from the spacing between them.
+///
+/// Positions are computed with integer arithmetic, so the same area always gives the same tiles.
+/// Once what is left is too small to be cut in two, it goes to the current item and the remaining
+/// items come back as zero-size rects at the origin of `area`.
+///
+/// # Returns
+///
+/// One rect per item, from the largest to the smallest.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::golden_grid;
+///
+/// let cells = golden_grid(Rect::new(0, 0, 100, 50), 3, 0);
+/// assert_eq!(cells[0], Rect::new(0, 0, 62, 50));
+/// assert_eq!(cells[1], Rect::new(62, 0, 38, 31));
+/// assert_eq!(cells[2], Rect::new(62, 31, 38, 19));
+/// ```
+pub fn golden_grid(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    tiles(area, n, |index, region| {
+        let direction = if index % 2 == 0 {
+            Direction::Horizontal
+        } else {
+            Direction::Vertical
+        };
+        cut(region, direction, spacing, golden_share)
+    })
+}
+
+/// Arranges `n` items in binary-split tiles, like the dwindle layout of tiling window managers.
+///
+/// The area is cut in half between the first item and the rest, then what is left is cut in half
+/// again between the next item and the rest, and so on. Each cut goes across the longer side of
+/// the region being cut, taking a terminal cell to be about twice as tall as it is wide: regions at
+/// least twice as wide as they are tall are cut side by side, and the others one above the other.
+/// When a length doesn't split evenly, the first half gets the extra cell. The last item gets
+/// whatever is left, so the tiles cover the whole area apart from the spacing between them.
+///
+/// Once what is left is too small to be cut in two, it goes to the current item and the remaining
+/// items come back as zero-size rects at the origin of `area`.
+///
+/// # Returns
+///
+/// One rect per item, in the order of the cuts.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::bsp_grid;
+///
+/// let cells = bsp_grid(Rect::new(0, 0, 100, 50), 3, 0);
+/// assert_eq!(cells[0], Rect::new(0, 0, 50, 50));
+/// assert_eq!(cells[1], Rect::new(50, 0, 50, 25));
+/// assert_eq!(cells[2], Rect::new(50, 25, 50, 25));
+/// ```
+pub fn bsp_grid(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    tiles(area, n, |_, region| {
+        let direction = if u32::from(region.width) >= 2 * u32::from(region.height) {
+            Direction::Horizontal
+        } else {
+            Direction::Vertical
+        };
+        cut(region, direction, spacing, |length| length.div_ceil(2))
+    })
+}
+
+/// Arranges `n` items in spiral tiles, each taking half of what the previous ones left.
+///
+/// The first item takes the left half of the area, the next one the top half of what is left,
+/// then the right half, then the bottom half, and so on: what is left moves right, down, left and
+/// up in turn, spiralling clockwise inwards. When a length doesn't split evenly, the tile gets the
+/// extra cell. The last item gets whatever is left, so the tiles cover the whole area apart from
+/// the spacing between them.
+///
+/// Once what is left is too small to be cut in two, it goes to the current item and the remaining
+/// items come back as zero-size rects at the origin of `area`.
+///
+/// # Returns
+///
+/// One rect per item, from the largest to the smallest.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::spiral_grid;
+///
+/// let cells = spiral_grid(Rect::new(0, 0, 40, 20), 4, 0);
+/// assert_eq!(cells[0], Rect::new(0, 0, 20, 20));
+/// assert_eq!(cells[1], Rect::new(20, 0, 20, 10));
+/// assert_eq!(cells[2], Rect::new(30, 10, 10, 10));
+/// assert_eq!(cells[3], Rect::new(20, 10, 10, 10));
+/// ```
+pub fn spiral_grid(area: Rect, n: usize, spacing: u16) -> Vec<Rect> {
+    tiles(area, n, |index, region| {
+        let direction = if index % 2 == 0 {
+            Direction::Horizontal
+        } else {
+            Direction::Vertical
+        };
+        if index % 4 < 2 {
+            // The tile is on the left or at the top, what is left after it.
+            cut(region, direction, spacing, |length| length.div_ceil(2))
+        } else {
+            // The tile is on the right or at the bottom, what is left before it.
+            let (rest, tile) = cut(region, direction, spacing, |length| length / 2)?;
+            Some((tile, rest))
+        }
+    })
+}
+
+/// Arranges `n` items in a master-stack layout, like the tiling of dwm.
+///
+/// The first `master_count` items are stacked one above the other in the left `master_ratio`
+/// percent of the width, and the other items are stacked in the rest of the width, with `spacing`
+/// between the two sides and between the items of a side. `master_ratio` is clamped to 1..=99 so
+/// that both sides get some room. When all the items are masters, or when there are no masters,
+/// the items are stacked in the whole width. An area too narrow for two sides goes to the masters.
+///
+/// Within a side, the items share the height like the rows of [`auto_grid`](crate::auto_grid).
+/// When a side has more items than rows, the items past the last row come back as zero-size rects
+/// at the origin of `area`.
+///
+/// # Example
+///
+/// ```
+/// # use ratatui_auto_grid::ratatui;
+/// use ratatui::layout::Rect;
+/// use ratatui_auto_grid::master_stack;
+///
+/// let cells = master_stack(Rect::new(0, 0, 100, 30), 4, 1, 60, 0);
+/// assert_eq!(cells[0], Rect::new(0, 0, 60, 30));
+/// assert_eq!(cells[1], Rect::new(60, 0, 40, 10));
+/// assert_eq!(cells[3], Rect::new(60, 20, 40, 10));
+/// ```
+pub fn master_stack(
+    area: Rect,
+    n: usize,
+    master_count: usize,
+    master_ratio: u32,
+    spacing: u16,
+) -> Vec<Rect> {
+    let masters = master_count.min(n);
+    if masters == 0 || masters == n {
+        return rows(area, n, spacing);
+    }
+
+    let ratio = u64::from(master_ratio.clamp(1, 99));
+    let share = |length: u16| ((u64::from(length) * ratio + 50) / 100) as u16;
+    let Some((master, stack)) = cut(area, Direction::Horizontal, spacing, share) else {
+        let mut out = rows(area, masters, spacing);
+        out.resize(n, empty_cell(area));
+        collapse_empty(area, &mut out);
+        return out;
+    };
+
+    let mut out = rows(master, masters, spacing);
+    out.extend(rows(stack, n - masters, spacing));
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Cuts a tile for each of `n` items off what is left of `area`.
+///
+/// `next` cuts the region left for the item at the given index into its tile and the rest, or
+/// returns `None` when the region can't be cut, in which case the item gets the whole region and
+/// the items after it zero-size rects at the origin of `area`. The last item gets the last region.
+fn tiles(
+    area: Rect,
+    n: usize,
+    mut next: impl FnMut(usize, Rect) -> Option<(Rect, Rect)>,
+) -> Vec<Rect> {
+    let mut out = Vec::with_capacity(n);
+    let mut rest = area;
+    for index in 0..n {
+        match (index + 1 < n).then(|| next(index, rest)).flatten() {
+            Some((tile, remaining)) => {
+                out.push(tile);
+                rest = remaining;
+            }
+            None => {
+                out.push(rest);
+                break;
+            }
+        }
+    }
+
+    out.resize(n, empty_cell(area));
+    collapse_empty(area, &mut out);
+    out
+}
+
+/// Returns the golden-ratio share of `length`, 1/φ of it rounded to the nearest cell.
+fn golden_share(length: u16) -> u16 {
+    ((u64::from(length) * 618_034 + 500_000) / 1_000_000) as u16
+}
+
+/// Cuts `region` in two along `direction`, with `spacing` between the parts.
+///
+/// `share` gives the length of the first part from the length available once the spacing is
+/// removed, and is kept so that both parts are at least 1 long. Returns `None` if the region is too
+/// small for two parts.
+fn cut(
+    region: Rect,
+    direction: Direction,
+    spacing: u16,
+    share: impl FnOnce(u16) -> u16,
+) -> Option<(Rect, Rect)> {
+    let length = match direction {
+        Direction::Horizontal => region.width,
+        Direction::Vertical => region.height,
+    };
+    let available = length
+        .checked_sub(spacing)
+        .filter(|&available| available >= 2)?;
+    if region.is_empty() {
+        return None;
+    }
+
+    let first = share(available).clamp(1, available - 1);
+    let second = available - first;
+    Some(match direction {
+        Direction::Horizontal => (
+            Rect {
+                width: first,
+                ..region
+            },
+            Rect {
+                x: region.x + first + spacing,
+                width: second,
+                ..region
+            },
+        ),
+        Direction::Vertical => (
+            Rect {
+                height: first,
+                ..region
+            },
+            Rect {
+                y: region.y + first + spacing,
+                height: second,
+                ..region
+            },
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the area of `rect`, which `Rect::area` saturates to a `u16` before ratatui 0.29.
+    fn area_of(rect: Rect) -> u32 {
+        u32::from(rect.width) * u32::from(rect.height)
+    }
+
+    #[test]
+    fn golden_single_tile_fills_the_area() {
+        let area = Rect::new(3, 4, 50, 20);
+        assert_eq!(golden_grid(area, 1, 2), [area]);
+        assert!(golden_grid(area, 0, 2).is_empty());
+    }
+
+    #[test]
+    fn golden_tiles_cover_the_area() {
+        let area = Rect::new(3, 4, 120, 40);
+        for n in 2..=5 {
+            let cells = golden_grid(area, n, 0);
+            assert_eq!(cells.len(), n);
+
+            let covered: u32 = cells.iter().copied().map(area_of).sum();
+            assert_eq!(covered, area_of(area), "n {n}");
+            for (i, a) in cells.iter().enumerate() {
+                assert_eq!(a.intersection(area), *a);
+                for b in &cells[i + 1..] {
+                    assert!(!a.intersects(*b), "{a:?} overlaps {b:?}");
+                }
+            }
+            // Each tile is larger than the next, apart from the last two which share a cut.
+            for pair in cells[..n - 1].windows(2) {
+                assert!(pair[0].area() > pair[1].area(), "n {n}: {pair:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn golden_spacing_between_tiles() {
+        let cells = golden_grid(Rect::new(0, 0, 101, 51), 3, 1);
+        assert_eq!(cells[0], Rect::new(0, 0, 62, 51));
+        assert_eq!(cells[1], Rect::new(63, 0, 38, 31));
+        assert_eq!(cells[2], Rect::new(63, 32, 38, 19));
+    }
+
+    #[test]
+    fn golden_is_stable_across_sizes() {
+        // Growing the area never moves a tile edge backwards.
+        let mut previous = golden_grid(Rect::new(0, 0, 40, 20), 4, 1);
+        for width in 41..=200 {
+            let cells = golden_grid(Rect::new(0, 0, width, 20), 4, 1);
+            assert!(cells[0].width >= previous[0].width);
+            assert_eq!(cells, golden_grid(Rect::new(0, 0, width, 20), 4, 1));
+            previous = cells;
+        }
+    }
+
+    #[test]
+    fn golden_small_remainders_stop_subdividing() {
+        let area = Rect::new(5, 5, 4, 2);
+        let cells = golden_grid(area, 5, 0);
+        assert_eq!(cells[0], Rect::new(5, 5, 2, 2));
+        assert_eq!(cells[1], Rect::new(7, 5, 2, 1));
+        assert_eq!(cells[2], Rect::new(7, 6, 1, 1));
+        assert_eq!(cells[3], Rect::new(8, 6, 1, 1));
+        assert_eq!(cells[4], Rect::new(5, 5, 0, 0));
+
+        assert_eq!(
+            golden_grid(Rect::new(1, 1, 10, 0), 3, 0),
+            [Rect::new(1, 1, 0, 0); 3]
+        );
+    }
+
+    #[test]
+    fn bsp_shapes() {
+        let area = Rect::new(0, 0, 100, 50);
+        assert_eq!(
+            bsp_grid(area, 3, 0),
+            [
+                Rect::new(0, 0, 50, 50),
+                Rect::new(50, 0, 50, 25),
+                Rect::new(50, 25, 50, 25),
+            ]
+        );
+        assert_eq!(
+            bsp_grid(area, 5, 0),
+            [
+                Rect::new(0, 0, 50, 50),
+                Rect::new(50, 0, 50, 25),
+                Rect::new(50, 25, 25, 25),
+                Rect::new(75, 25, 25, 13),
+                Rect::new(75, 38, 25, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn bsp_tiles_cover_the_area() {
+        let area = Rect::new(3, 4, 97, 41);
+        for n in 1..=9 {
+            let cells = bsp_grid(area, n, 0);
+            let covered: u32 = cells.iter().copied().map(area_of).sum();
+            assert_eq!(covered, area_of(area), "n {n}");
+            for (i, a) in cells.iter().enumerate() {
+                for b in &cells[i + 1..] {
+                    assert!(!a.intersects(*b), "{a:?} overlaps {b:?}");
+                }
+            }
+        }
+
+        let cells = bsp_grid(Rect::new(0, 0, 101, 50), 2, 1);
+        assert_eq!(cells, [Rect::new(0, 0, 50, 50), Rect::new(51, 0, 50, 50)]);
+        assert_eq!(bsp_grid(Rect::new(0, 0, 2, 1), 4, 0)[2..], [Rect::ZERO; 2]);
+    }
+
+    #[test]
+    fn master_stack_sides() {
+        let area = Rect::new(2, 3, 101, 31);
+        let cells = master_stack(area, 5, 2, 50, 1);
+        assert_eq!(
+            cells,
+            [
+                Rect::new(2, 3, 50, 15),
+                Rect::new(2, 19, 50, 15),
+                Rect::new(53, 3, 50, 10),
+                Rect::new(53, 14, 50, 10),
+                Rect::new(53, 25, 50, 9),
+            ]
+        );
+
+        for masters in [0, 5, 9] {
+            assert_eq!(master_stack(area, 5, masters, 50, 1), rows(area, 5, 1));
+        }
+    }
+
+    #[test]
+    fn master_ratio_is_clamped() {
+        let area = Rect::new(0, 0, 100, 10);
+        let cells = master_stack(area, 2, 1, 0, 0);
+        assert_eq!(cells, [Rect::new(0, 0, 1, 10), Rect::new(1, 0, 99, 10)]);
+        let cells = master_stack(area, 2, 1, 250, 0);
+        assert_eq!(cells, [Rect::new(0, 0, 99, 10), Rect::new(99, 0, 1, 10)]);
+
+        // Too narrow for two sides.
+        let cells = master_stack(Rect::new(4, 4, 2, 10), 3, 1, 50, 1);
+        assert_eq!(cells[0], Rect::new(4, 4, 2, 10));
+        assert_eq!(cells[1..], [Rect::new(4, 4, 0, 0); 2]);
+    }
+
+    #[test]
+    fn master_stack_overflow_is_zeroed_from_the_end() {
+        let area = Rect::new(0, 0, 20, 4);
+        let cells = master_stack(area, 7, 1, 50, 0);
+        assert_eq!(cells[0], Rect::new(0, 0, 10, 4));
+        for (i, cell) in cells[1..5].iter().enumerate() {
+            assert_eq!(*cell, Rect::new(10, i as u16, 10, 1));
+        }
+        assert_eq!(cells[5..], [Rect::ZERO; 2]);
+    }
+
+    #[test]
+    fn spiral_of_five() {
+        let area = Rect::new(0, 0, 33, 13);
+        assert_eq!(
+            crate::debug_render(area, &spiral_grid(area, 5, 1)),
+            "\
+0--------------+ 1--------------+
+|              | |              |
+|              | |              |
+|              | |              |
+|              | |              |
+|              | +--------------+
+|              |
+|              | 4-----+ 2------+
+|              | +-----+ |      |
+|              |         |      |
+|              | 3-----+ |      |
+|              | |     | |      |
++--------------+ +-----+ +------+
+"
+        );
+    }
+
+    #[test]
+    fn spiral_terminates_in_small_areas() {
+        for (width, height) in [(40, 20), (7, 5), (3, 3), (1, 1), (0, 4)] {
+            let area = Rect::new(5, 6, width, height);
+            for spacing in [0, 1, 3] {
+                let cells = spiral_grid(area, 9, spacing);
+                assert_eq!(cells.len(), 9);
+                for (i, a) in cells.iter().enumerate() {
+                    assert_eq!(a.intersection(area), *a, "{area:?}, spacing {spacing}");
+                    for b in &cells[i + 1..] {
+                        assert!(!a.intersects(*b), "{a:?} overlaps {b:?}");
+                    }
+                }
+            }
+        }
+
+        let cells = spiral_grid(Rect::new(5, 6, 2, 2), 6, 0);
+        assert_eq!(
+            cells[..4],
+            [
+                Rect::new(5, 6, 1, 2),
+                Rect::new(6, 6, 1, 1),
+                Rect::new(6, 7, 1, 1),
+                Rect::new(5, 6, 0, 0),
+            ]
+        );
+    }
+}
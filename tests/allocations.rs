@@ -0,0 +1,48 @@
+//! Counts the allocations made by `auto_grid` with a counting global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use ratatui_auto_grid::auto_grid;
+use ratatui_auto_grid::ratatui::layout::Rect;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by `f` on the current thread.
+fn allocations<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (ALLOCATIONS.with(Cell::get) - before, result)
+}
+
+#[test]
+fn allocates_the_cells_and_the_columns_only() {
+    let area = Rect::new(0, 0, 140, 70);
+    for n in [1, 4, 9, 16, 49, 1000] {
+        let (count, cells) = allocations(|| auto_grid(area, n, 1));
+        assert_eq!(cells.len(), n);
+        assert_eq!(count, 2, "n = {n}");
+    }
+
+    let (count, cells) = allocations(|| auto_grid(area, 0, 1));
+    assert!(cells.is_empty());
+    assert_eq!(count, 0);
+}